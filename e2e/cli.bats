@@ -1,5 +1,12 @@
 # bats test_tags=cli
 @test "not existing config file" {
     run bash -c 'echo "feat(cli): impl -a flag" | commitlint --config not-existing-config.js'
+    [ "$status" -eq 2 ]
+}
+
+# bats test_tags=cli
+@test "batch" {
+    run bash -c 'printf "feat(cli): impl -a flag\n\0\nimpl -b flag\n\0\n" | commitlint --batch'
     [ "$status" -eq 1 ]
+    [[ "$output" == *"commit 2: "* ]]
 }
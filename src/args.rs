@@ -1,13 +1,15 @@
 use std::{
-    fmt::Error,
-    io::{stdin, IsTerminal, Read},
+    io::{stdin, stdout, IsTerminal, Read, Write},
     path::PathBuf,
 };
 
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 
-use crate::git::{self, ReadCommitMessageOptions};
-use crate::message::Message;
+use commitlint_rs::git::{self, Commit, PushRange, ReadCommitMessageOptions};
+use commitlint_rs::message::Message;
+
+/// Path to the commit message file edited by git.
+const COMMIT_EDITMSG: &str = "./.git/COMMIT_EDITMSG";
 
 /// Cli represents the command line arguments.
 ///
@@ -16,6 +18,16 @@ use crate::message::Message;
 #[derive(Parser, Debug)]
 #[command(author, about = "CLI to lint with conventional commits", long_about = None, version)]
 pub struct Args {
+    /// Read multiple commit messages separated by a NUL byte from stdin
+    ///
+    /// This matches the output of `git log --format=%B%x00`.
+    #[arg(long)]
+    pub batch: bool,
+
+    /// Force the colored output even if it is not a terminal
+    #[arg(long, conflicts_with = "no_color")]
+    pub color: bool,
+
     /// Path to the config file
     #[arg(short = 'g', long)]
     pub config: Option<PathBuf>,
@@ -24,24 +36,133 @@ pub struct Args {
     #[arg(short = 'd', long, default_value = ".")]
     pub cwd: String,
 
+    /// Print each rule with its result and elapsed time to stderr
+    ///
+    /// This helps to find out why a commit message passed or failed.
+    #[arg(long)]
+    pub debug: bool,
+
     /// Read last commit from the specified file or fallbacks to ./.git/COMMIT_EDITMSG
-    #[arg(short = 'e', long)]
-    pub edit: bool,
+    ///
+    /// In the `commit-msg` hook, pass the path given by git such as `--edit "$1"`.
+    #[arg(short = 'e', long, value_name = "PATH", num_args = 0..=1, default_missing_value = COMMIT_EDITMSG)]
+    pub edit: Option<PathBuf>,
+
+    /// Fix the commit messages automatically where possible
+    ///
+    /// The fixed message is written back to the file with --edit, or printed to stdout otherwise.
+    /// The fixed messages printed to stdout are separated by a NUL byte as the input of --batch,
+    /// and the lint results are printed to stderr instead.
+    #[arg(long)]
+    pub fix: bool,
+
+    /// Output format of the lint results
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+
+    /// List all the errors first and then all the warnings, each under a header
+    ///
+    /// Only the text format is grouped.
+    #[arg(long)]
+    pub group_by_level: bool,
 
     /// Lower end of the commit range to lint
     #[arg(short = 'f', long)]
     pub from: Option<String>,
 
+    /// Disable the colored output
+    ///
+    /// The colored output is also disabled by the NO_COLOR environment variable.
+    /// See: https://no-color.org
+    #[arg(long)]
+    pub no_color: bool,
+
     /// Print resolved config
     #[arg(long = "print-config")]
     pub print_config: bool,
 
+    /// Suppress the output of the lint results
+    ///
+    /// The exit status is still non-zero if there is any error-level violation.
+    #[arg(short = 'q', long, conflicts_with = "verbose")]
+    pub quiet: bool,
+
+    /// Range of the pushed commits to lint such as `<old-sha>..<new-sha>`
+    ///
+    /// The results are reported per commit with the SHAs, e.g. for the server-side hooks.
+    /// If the old SHA is all zeros for the initial push, the commits reachable from the new SHA
+    /// but not from any remote-tracking branch are linted.
+    #[arg(long, value_name = "OLD..NEW", conflicts_with_all = ["batch", "edit", "from", "to"])]
+    pub range: Option<String>,
+
+    /// Fail the lint on the warning-level violations as well as the error-level ones
+    ///
+    /// The violations are still reported with their original levels.
+    /// It can also be enabled with `strict: true` in the config file.
+    #[arg(long)]
+    pub strict: bool,
+
+    /// Maximum number of threads to lint the commit messages
+    ///
+    /// Defaults to the number of available CPUs.
+    #[arg(long)]
+    pub threads: Option<usize>,
+
     /// Upper end of the commit range to lint
     #[arg(short = 't', long)]
     pub to: Option<String>,
+
+    /// Print the summary line even if there is no problem
+    #[arg(long)]
+    pub verbose: bool,
+}
+
+/// Format represents the output format of the lint results.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum Format {
+    /// Human-readable messages
+    Text,
+
+    /// Machine-readable JSON
+    Json,
+
+    /// SARIF 2.1.0 for the code scanning services
+    Sarif,
+
+    /// JUnit XML for the CI test reporters
+    Junit,
 }
 
 impl Args {
+    /// Check whether the output written to the stream is colored.
+    ///
+    /// The flags take precedence over the NO_COLOR environment variable,
+    /// and the output is colored only for a terminal otherwise.
+    pub fn color(&self, stream: &impl IsTerminal) -> bool {
+        if self.color {
+            return true;
+        }
+
+        if self.no_color {
+            return false;
+        }
+
+        if std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+            return false;
+        }
+
+        stream.is_terminal()
+    }
+
+    /// Get the number of threads to lint the commit messages.
+    pub fn threads(&self) -> usize {
+        self.threads.unwrap_or_else(|| {
+            std::thread::available_parallelism()
+                .map(|threads| threads.get())
+                .unwrap_or(1)
+        })
+    }
+
     /// Check wether the commit message is from stdin or not.
     ///
     /// Inspired by https://github.com/conventional-changelog/commitlint/blob/af2f3a82d38ea0272578c8066565a0e6cf5810b0/%40commitlint/cli/src/cli.ts#L336
@@ -49,14 +170,32 @@ impl Args {
         !stdin().is_terminal()
     }
 
+    /// Read the commits of the push range given by --range from git.
+    /// Returns `None` if --range is not given, and an error if the range is invalid or unknown to git.
+    pub fn read_push_range(&self) -> Result<Option<Vec<Commit>>, String> {
+        match &self.range {
+            Some(range) => {
+                let range = PushRange::parse(range)?;
+                Ok(Some(git::read_push_range(&range, &self.cwd)?))
+            }
+            None => Ok(None),
+        }
+    }
+
     /// Read commit messages from stdin.
-    pub fn read(&self) -> Result<Vec<Message>, Error> {
+    ///
+    /// The comment lines starting with `comment_char` are stripped from the edited message,
+    /// and the subjects are parsed with the `separator` between the type and the description.
+    /// Returns an error if the file given by --edit cannot be read.
+    pub fn read(&self, comment_char: char, separator: &str) -> Result<Vec<Message>, String> {
+        let new = |raw: String| Message::with_separator(raw, separator);
+
         // Check first whether or not the --edit option was supplied. When running from tooling such as
         // `pre-commit`, stdin exists, so this needs to come first.
-        if self.edit {
-            let msg = std::fs::read_to_string("./.git/COMMIT_EDITMSG")
-                .expect("Failed to read './.git/COMMIT_EDITMSG'");
-            return Ok(vec![Message::new(msg)]);
+        if let Some(path) = &self.edit {
+            let msg = std::fs::read_to_string(path)
+                .map_err(|err| format!("cannot read '{}': {}", path.display(), err))?;
+            return Ok(vec![new(git::strip_comments(&msg, comment_char))]);
         }
 
         // Otherwise, check for stdin and use the incoming text buffer from there if so.
@@ -65,7 +204,16 @@ impl Args {
             stdin()
                 .read_to_string(&mut buffer)
                 .expect("Failed to read commit messages from stdin");
-            return Ok(vec![Message::new(buffer)]);
+
+            if self.batch {
+                let messages = git::split_commit_messages(&buffer)
+                    .into_iter()
+                    .map(new)
+                    .collect();
+                return Ok(messages);
+            }
+
+            return Ok(vec![new(git::strip_comments(&buffer, comment_char))]);
         }
 
         // And if none of the above, we're expecting to be reading directly from Git...
@@ -77,9 +225,40 @@ impl Args {
 
         let messages = git::read(config)
             .iter()
-            .map(|s| Message::new(s.to_string()))
+            .map(|s| new(s.to_string()))
             .collect();
 
         Ok(messages)
     }
+
+    /// Check whether the fixed commit messages are printed to stdout,
+    /// in which case the lint results must not be printed to stdout.
+    pub fn prints_fixed(&self) -> bool {
+        self.fix && self.edit.is_none()
+    }
+
+    /// Write the fixed commit messages.
+    ///
+    /// With the --edit option, the message is written back to the file.
+    /// Otherwise, the messages are printed to stdout as they are,
+    /// each followed by a NUL byte if multiple messages can be given.
+    pub fn write(&self, messages: &[Message]) -> std::io::Result<()> {
+        if let Some(path) = &self.edit {
+            if let Some(message) = messages.first() {
+                std::fs::write(path, &message.raw)?;
+            }
+            return Ok(());
+        }
+
+        let separated = self.batch || messages.len() > 1;
+        let mut stdout = stdout().lock();
+        for message in messages {
+            stdout.write_all(message.raw.as_bytes())?;
+            if separated {
+                stdout.write_all(b"\0")?;
+            }
+        }
+
+        stdout.flush()
+    }
 }
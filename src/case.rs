@@ -0,0 +1,156 @@
+use serde::{Deserialize, Deserializer};
+
+/// Case represents a casing convention of a text.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Case {
+    /// camelCase
+    Camel,
+
+    /// kebab-case
+    Kebab,
+
+    /// lower case
+    Lower,
+
+    /// Sentence case
+    Sentence,
+
+    /// snake_case
+    Snake,
+
+    /// Start Case
+    Start,
+
+    /// Train-Case
+    Train,
+
+    /// UPPER CASE
+    Upper,
+}
+
+/// Cases in the order used to detect the case of a text.
+const CASES: [Case; 8] = [
+    Case::Lower,
+    Case::Upper,
+    Case::Sentence,
+    Case::Start,
+    Case::Camel,
+    Case::Kebab,
+    Case::Snake,
+    Case::Train,
+];
+
+impl Case {
+    /// Get the case from the name used in the configuration.
+    pub fn from_name(name: &str) -> Option<Self> {
+        CASES.into_iter().find(|case| case.name() == name)
+    }
+
+    /// Name of the case used in the configuration.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Case::Camel => "camel-case",
+            Case::Kebab => "kebab-case",
+            Case::Lower => "lower-case",
+            Case::Sentence => "sentence-case",
+            Case::Snake => "snake-case",
+            Case::Start => "start-case",
+            Case::Train => "train-case",
+            Case::Upper => "upper-case",
+        }
+    }
+
+    /// Check whether the text is written in the case.
+    pub fn matches(&self, text: &str) -> bool {
+        match self {
+            Case::Camel => {
+                let mut chars = text.chars();
+                chars.next().is_some_and(|c| c.is_lowercase()) && chars.all(|c| c.is_alphanumeric())
+            }
+            Case::Kebab => is_delimited(text, '-'),
+            Case::Lower => text == text.to_lowercase(),
+            Case::Sentence => text == capitalize(&text.to_lowercase()),
+            Case::Snake => is_delimited(text, '_'),
+            Case::Start => text
+                .split_whitespace()
+                .all(|word| word == capitalize(&word.to_lowercase())),
+            Case::Train => text
+                .split('-')
+                .all(|word| !word.is_empty() && word == capitalize(&word.to_lowercase())),
+            Case::Upper => text == text.to_uppercase(),
+        }
+    }
+}
+
+/// Deserialize the names of the cases used in the configuration.
+/// Unknown names are rejected so that a misspelled case does not silently disable the check.
+pub fn deserialize_names<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let names = Vec::<String>::deserialize(deserializer)?;
+    if let Some(name) = names.iter().find(|name| Case::from_name(name).is_none()) {
+        return Err(serde::de::Error::custom(format!(
+            "unknown case {:?}, expected one of {:?}",
+            name,
+            CASES.map(|case| case.name())
+        )));
+    }
+
+    Ok(names)
+}
+
+/// Detect the case of the text.
+/// Returns the first matching case or `None` if the text is in mixed case.
+pub fn detect(text: &str) -> Option<Case> {
+    CASES.into_iter().find(|case| case.matches(text))
+}
+
+/// Check whether the text consists of lowercase words joined by the delimiter.
+fn is_delimited(text: &str, delimiter: char) -> bool {
+    text.split(delimiter)
+        .all(|word| !word.is_empty() && word.chars().all(|c| c.is_lowercase() || c.is_numeric()))
+}
+
+/// Uppercase the first character of the text.
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(Case::from_name("lower-case"), Some(Case::Lower));
+        assert_eq!(Case::from_name("unknown-case"), None);
+    }
+
+    #[test]
+    fn test_detect() {
+        assert_eq!(detect("add new flag"), Some(Case::Lower));
+        assert_eq!(detect("ADD NEW FLAG"), Some(Case::Upper));
+        assert_eq!(detect("Add new flag"), Some(Case::Sentence));
+        assert_eq!(detect("Add New Flag"), Some(Case::Start));
+        assert_eq!(detect("addNewFlag"), Some(Case::Camel));
+        assert_eq!(detect("aDD nEW FLAG"), None);
+    }
+
+    #[test]
+    fn test_delimited_cases() {
+        assert!(Case::Kebab.matches("my-feature"));
+        assert!(!Case::Kebab.matches("my_feature"));
+        assert!(!Case::Kebab.matches("my--feature"));
+        assert!(Case::Snake.matches("my_feature"));
+        assert!(!Case::Snake.matches("myFeature"));
+        assert!(!Case::Camel.matches("my-feature"));
+        assert!(Case::Train.matches("Reviewed-By"));
+        assert!(!Case::Train.matches("Reviewed-by"));
+        assert!(!Case::Train.matches("signed-off-by"));
+    }
+}
@@ -1,8 +1,17 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
-use std::{fs, path::PathBuf};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
 
-use crate::rule::Rules;
+use crate::{
+    git,
+    message::Message,
+    result::Violation,
+    rule::{DynBatchRule, DynRule, Level, Rules},
+};
 
 /// Default Root config file path to search for.
 const DEFAULT_CONFIG_ROOT: &str = ".";
@@ -17,11 +26,263 @@ const DEFAULT_CONFIG_FILE: [&str; 4] = [
     ".commitlintrc.yml",
 ];
 
+/// Built-in patterns of the commit messages to be skipped if `default-ignores` is enabled,
+/// each with the rules and the options linting such commit messages.
+/// A pattern is not used while any of them is configured, so that they are not disabled by default.
+const DEFAULT_IGNORES: [(&str, &[&str]); 6] = [
+    // Merge commits such as `Merge branch 'main' into feature`.
+    (r"^Merge ", &[]),
+    // Revert commits generated by `git revert`.
+    (
+        r#"^Revert ""#,
+        &["revert-hash", "revert-subject-match", "ignore-revert"],
+    ),
+    // Commits created by `git commit --fixup` and `--squash`.
+    (r"^(?:fixup|squash|amend)! ", &["no-fixup"]),
+    // Version bumps such as `v1.2.3`.
+    (r"^v?\d+\.\d+\.\d+\S*[ \t]*(?:\r?\n|$)", &[]),
+    // Release commits such as `chore(release): 1.2.3`.
+    (r"^chore\(release\): v?\d+\.\d+\.\d+", &[]),
+    // Commits automatically created by the hosting services.
+    (r"^Automatic merge ", &[]),
+];
+
 /// Config represents the configuration of commitlint.
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Config {
+    /// Extends represents the paths to the base configuration files.
+    /// The paths are relative to the configuration file extending them.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub extends: Vec<String>,
+
     /// Rules represents the rules of commitlint.
     pub rules: Rules,
+
+    /// IgnoreRevert represents the names of the rules skipped for the revert commits.
+    #[serde(default, rename = "ignore-revert")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub ignore_revert: Vec<String>,
+
+    /// IgnoreMerge represents whether the merge commits are skipped.
+    #[serde(default, rename = "ignore-merge")]
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub ignore_merge: bool,
+
+    /// MergePrefixes represents the prefixes of the subject of the merge commits.
+    /// If it is not specified, the subjects starting with `Merge ` are merge commits.
+    #[serde(rename = "merge-prefixes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merge_prefixes: Option<Vec<String>>,
+
+    /// Ignores represents the regex patterns of the commit messages to be skipped.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub ignores: Vec<String>,
+
+    /// DefaultIgnores represents whether the built-in `ignores` patterns are used.
+    #[serde(default = "default_default_ignores", rename = "default-ignores")]
+    pub default_ignores: bool,
+
+    /// Severity represents the levels overriding the ones of the rules by the rule name.
+    /// It is applied to the violations, so that the built-in error rules can be
+    /// downgraded to warnings without editing the options of each rule.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub severity: BTreeMap<String, Level>,
+
+    /// Strict represents whether the warning-level violations also fail the lint.
+    /// The levels of the violations are reported as they are.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub strict: bool,
+
+    /// CommentChar represents the character starting the comment lines of the edited commit message.
+    /// It should be the same as `core.commentChar` of git.
+    #[serde(default = "default_comment_char", rename = "comment-char")]
+    pub comment_char: char,
+
+    /// Separator represents the separator between the `type(scope)` prefix and the description.
+    /// It can be changed for the workflows using such as `feat(cli)/ add new flag`.
+    #[serde(default = "default_separator")]
+    pub separator: String,
+}
+
+/// The built-in `ignores` patterns are used by default.
+fn default_default_ignores() -> bool {
+    true
+}
+
+/// Comment lines start with `#` by default as git does.
+fn default_comment_char() -> char {
+    '#'
+}
+
+/// The prefix and the description are separated by `:` by default as Conventional Commits.
+fn default_separator() -> String {
+    git::DEFAULT_SEPARATOR.to_string()
+}
+
+/// Default implementation of Config.
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            extends: Vec::new(),
+            rules: Rules::default(),
+            ignore_revert: Vec::new(),
+            ignore_merge: false,
+            merge_prefixes: None,
+            ignores: Vec::new(),
+            default_ignores: default_default_ignores(),
+            severity: BTreeMap::new(),
+            strict: false,
+            comment_char: default_comment_char(),
+            separator: default_separator(),
+        }
+    }
+}
+
+impl Config {
+    /// Check whether the message is a merge commit.
+    pub fn is_merge(&self, message: &Message) -> bool {
+        match (&self.merge_prefixes, &message.subject) {
+            (Some(prefixes), Some(subject)) => prefixes
+                .iter()
+                .any(|prefix| subject.starts_with(prefix.as_str())),
+            (Some(_), None) => false,
+            (None, _) => message.is_merge(),
+        }
+    }
+
+    /// Check whether any of the rules or the options is configured.
+    /// `ignore-revert` is configured if it lists any rule.
+    fn is_configured(&self, targets: &[&str]) -> bool {
+        if targets.is_empty() {
+            return false;
+        }
+
+        let rules = self.rules.rules();
+        let batch_rules = self.rules.batch_rules();
+        targets.iter().any(|target| match *target {
+            "ignore-revert" => !self.ignore_revert.is_empty(),
+            target => {
+                rules
+                    .iter()
+                    .any(|(name, rule)| *name == target && rule.is_some())
+                    || batch_rules
+                        .iter()
+                        .any(|(name, rule)| *name == target && rule.is_some())
+            }
+        })
+    }
+
+    /// Check whether the raw commit message matches any of the `ignores` patterns.
+    /// The built-in patterns are also checked if `default-ignores` is enabled,
+    /// except for the ones whose commit messages are linted by the configured rules.
+    pub fn is_ignored(&self, message: &Message) -> Result<bool, regex::Error> {
+        let defaults: &[(&str, &[&str])] = if self.default_ignores {
+            &DEFAULT_IGNORES
+        } else {
+            &[]
+        };
+
+        let patterns = defaults
+            .iter()
+            .filter(|(_, targets)| !self.is_configured(targets))
+            .map(|(pattern, _)| *pattern)
+            .chain(self.ignores.iter().map(|pattern| pattern.as_str()));
+
+        for pattern in patterns {
+            if crate::pattern::compile(pattern)?.is_match(&message.raw) {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Validate the commit message with the rules.
+    ///
+    /// The commit messages matching the `ignores` patterns and the merge commits
+    /// with `ignore-merge` are not validated at all.
+    /// The rules listed in `ignore-revert` are skipped for the revert commits.
+    /// The levels of the violations are overridden by `severity` at last.
+    pub fn validate(&self, message: &Message) -> Vec<Violation> {
+        self.validate_with(message, |rule, message| rule.validate_all(message))
+    }
+
+    /// Validate the commit message in the same way as `validate`,
+    /// but with `run` running each configured rule.
+    ///
+    /// It lets the callers observe the rules without changing the result,
+    /// such as the trace of the `--debug` flag.
+    pub fn validate_with<F>(&self, message: &Message, run: F) -> Vec<Violation>
+    where
+        F: FnMut(&dyn DynRule, &Message) -> Vec<Violation>,
+    {
+        match self.is_ignored(message) {
+            Ok(true) => return Vec::new(),
+            Ok(false) => {}
+            Err(err) => {
+                return vec![Violation {
+                    level: Level::Error,
+                    message: err.to_string(),
+                    rule: "ignores".to_string(),
+                }]
+            }
+        }
+
+        if self.ignore_merge && self.is_merge(message) {
+            return Vec::new();
+        }
+
+        let mut violations = self.rules.validate_with(message, run);
+
+        if message.is_revert() {
+            violations.retain(|violation| !self.ignore_revert.contains(&violation.rule));
+        }
+
+        self.override_severity(violations)
+    }
+
+    /// Validate the commit messages together with the batch rules
+    /// when multiple commit messages are given.
+    ///
+    /// The commit messages skipped by `validate` are not validated either.
+    pub fn validate_batch(&self, messages: &[Message]) -> Vec<Violation> {
+        self.validate_batch_with(messages, |rule, messages| rule.validate_batch(messages))
+    }
+
+    /// Validate the commit messages in the same way as `validate_batch`,
+    /// but with `run` running each configured batch rule.
+    pub fn validate_batch_with<F>(&self, messages: &[Message], run: F) -> Vec<Violation>
+    where
+        F: FnMut(&dyn DynBatchRule, &[Message]) -> Vec<Violation>,
+    {
+        let messages: Vec<Message> = messages
+            .iter()
+            .filter(|message| !self.is_ignored(message).unwrap_or(false))
+            .filter(|message| !(self.ignore_merge && self.is_merge(message)))
+            .cloned()
+            .collect();
+        if messages.len() < 2 {
+            return Vec::new();
+        }
+
+        self.override_severity(self.rules.validate_batch_with(&messages, run))
+    }
+
+    /// Override the levels of the violations by `severity`.
+    fn override_severity(&self, mut violations: Vec<Violation>) -> Vec<Violation> {
+        for violation in violations.iter_mut() {
+            if let Some(level) = self.severity.get(&violation.rule) {
+                violation.level = *level;
+            }
+        }
+        violations.retain(|violation| violation.level != Level::Off);
+
+        violations
+    }
 }
 
 impl fmt::Display for Config {
@@ -49,22 +310,41 @@ pub async fn load(path: Option<PathBuf>) -> Result<Config, String> {
 }
 
 /// Find configuration file in the specified path.
+/// If no file is found, the parent directories are searched up to the root of the repository.
 /// Note that the first file found will be returned.
 pub fn find_config_file(path: PathBuf) -> Option<PathBuf> {
-    let mut path = path;
-    for file in DEFAULT_CONFIG_FILE.iter() {
-        path.push(file);
-        if path.exists() {
-            return Some(path);
+    let path = path.canonicalize().unwrap_or(path);
+    for dir in path.ancestors() {
+        for file in DEFAULT_CONFIG_FILE.iter() {
+            let path = dir.join(file);
+            if path.exists() {
+                return Some(path);
+            }
+        }
+
+        // Stop searching at the root of the repository.
+        if dir.join(".git").exists() {
+            break;
         }
-        path.pop();
     }
 
     None
 }
 
 /// Load config file from the specified path.
+///
+/// The configuration files listed in `extends` are loaded first, and the
+/// configuration is deep-merged on top of them, so that the local settings take precedence.
 pub async fn load_config_file(path: PathBuf) -> Result<Config, String> {
+    let value = load_config_value(&path, &mut Vec::new())?;
+
+    serde_json::from_value::<Config>(value)
+        .map_err(|err| format!("Failed to parse configuration file: {}", err))
+}
+
+/// Load the configuration file as a value with the `extends` resolved.
+/// The paths of the files being loaded are kept in `stack` to detect the cyclic extends.
+fn load_config_value(path: &Path, stack: &mut Vec<PathBuf>) -> Result<serde_json::Value, String> {
     if !path.exists() {
         return Err(format!(
             "Configuration file not found in {}",
@@ -72,12 +352,70 @@ pub async fn load_config_file(path: PathBuf) -> Result<Config, String> {
         ));
     }
 
-    match path.extension() {
-        Some(ext) => match ext.to_str() {
-            Some("json") => load_json_config_file(path).await,
-            Some("yaml") | Some("yml") => load_yaml_config_file(path).await,
-            _ => load_unknown_config_file(path).await,
-        },
+    let canonical = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    if stack.contains(&canonical) {
+        let cycle: Vec<String> = stack
+            .iter()
+            .chain(std::iter::once(&canonical))
+            .map(|path| path.display().to_string())
+            .collect();
+        return Err(format!("Cyclic extends: {}", cycle.join(" -> ")));
+    }
+
+    let value = parse_config_file(path)?;
+
+    // The paths of the base configurations are relative to the extending one.
+    let dir = path.parent().unwrap_or_else(|| Path::new("."));
+    let extends: Vec<String> = match value.get("extends") {
+        Some(extends) => serde_json::from_value(extends.clone())
+            .map_err(|err| format!("Failed to parse configuration file: {}", err))?,
+        None => Vec::new(),
+    };
+
+    stack.push(canonical);
+    let mut merged = serde_json::Value::Object(serde_json::Map::new());
+    for base in extends {
+        let base = load_config_value(&dir.join(base), stack)?;
+        merge(&mut merged, base);
+    }
+    stack.pop();
+
+    merge(&mut merged, value);
+    Ok(merged)
+}
+
+/// Deep-merge the value into the base.
+/// The objects are merged recursively, and the other values are replaced.
+fn merge(base: &mut serde_json::Value, value: serde_json::Value) {
+    match (base, value) {
+        (serde_json::Value::Object(base), serde_json::Value::Object(value)) => {
+            for (key, value) in value {
+                match base.get_mut(&key) {
+                    Some(existing) => merge(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, value) => *base = value,
+    }
+}
+
+/// Parse the configuration file as a value by its extension.
+fn parse_config_file(path: &Path) -> Result<serde_json::Value, String> {
+    let text = fs::read_to_string(path)
+        .map_err(|err| format!("Failed to read configuration file: {}", err))?;
+
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("json") => serde_json::from_str(&text)
+            .map_err(|err| format!("Failed to parse configuration file: {}", err)),
+        Some("yaml") | Some("yml") => serde_yaml::from_str(&text)
+            .map_err(|err| format!("Failed to parse configuration file: {}", err)),
+        // Try to load it as JSON, then as YAML.
+        Some(_) => serde_json::from_str(&text)
+            .or_else(|_| serde_yaml::from_str(&text))
+            .map_err(|_| format!("Failed to parse configuration file: {}", path.display())),
         None => Err(format!(
             "Unsupported configuration file format: {}",
             path.display()
@@ -85,42 +423,465 @@ pub async fn load_config_file(path: PathBuf) -> Result<Config, String> {
     }
 }
 
-/// Load JSON config file from the specified path.
-async fn load_json_config_file(path: PathBuf) -> Result<Config, String> {
-    let text = fs::read_to_string(path).unwrap();
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    match serde_json::from_str::<Config>(&text) {
-        Ok(config) => Ok(config),
-        Err(err) => Err(format!("Failed to parse configuration file: {}", err)),
+    /// Create an empty temporary directory for the test.
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("commitlint-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
     }
-}
 
-/// Load YAML config file from the specified path.
-async fn load_yaml_config_file(path: PathBuf) -> Result<Config, String> {
-    let text = fs::read_to_string(path).unwrap();
+    #[test]
+    fn test_find_config_file_in_parent() {
+        let root = temp_dir("find-parent");
+        fs::create_dir(root.join(".git")).unwrap();
+        fs::write(root.join(".commitlintrc.yaml"), "rules: {}").unwrap();
+        let nested = root.join("crates").join("cli");
+        fs::create_dir_all(&nested).unwrap();
+
+        let found = find_config_file(nested);
+        assert_eq!(
+            found,
+            Some(root.canonicalize().unwrap().join(".commitlintrc.yaml"))
+        );
 
-    match serde_yaml::from_str::<Config>(&text) {
-        Ok(config) => Ok(config),
-        Err(err) => Err(format!("Failed to parse configuration file: {}", err)),
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_find_config_file_stops_at_repository_root() {
+        let root = temp_dir("find-root");
+        fs::write(root.join(".commitlintrc.yaml"), "rules: {}").unwrap();
+        let repo = root.join("repo");
+        fs::create_dir_all(repo.join(".git")).unwrap();
+
+        assert_eq!(find_config_file(repo), None);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_load_yaml_config_file() {
+        let root = temp_dir("load-yaml");
+        let path = root.join(".commitlintrc.yaml");
+        fs::write(
+            &path,
+            "rules:
+  description-format:
+    level: warning
+    format: ^[A-Z]+-[0-9]+ .*$
+",
+        )
+        .unwrap();
+
+        let config = load_config_file(path).await.unwrap();
+
+        let violations = config
+            .rules
+            .validate(&Message::new("feat(cli): ABC-123 add new flag".to_string()));
+        assert!(violations
+            .iter()
+            .all(|violation| violation.rule != "description-format"));
+
+        let violations = config
+            .rules
+            .validate(&Message::new("feat(cli): add new flag".to_string()));
+        let violation = violations
+            .iter()
+            .find(|violation| violation.rule == "description-format")
+            .unwrap();
+        assert_eq!(violation.level, Level::Warning);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_load_config_file_with_extends() {
+        let root = temp_dir("extends");
+        fs::create_dir_all(root.join("shared")).unwrap();
+        fs::write(
+            root.join("shared/base.yaml"),
+            "rules:
+  scope-enum:
+    level: error
+    allowed:
+      - cli
+  body-empty:
+    level: error
+ignores:
+  - ^WIP
+",
+        )
+        .unwrap();
+        let path = root.join(".commitlintrc.yaml");
+        fs::write(
+            &path,
+            "extends:
+  - shared/base.yaml
+rules:
+  scope-enum:
+    level: warning
+  body-empty:
+    level: off
+",
+        )
+        .unwrap();
+
+        let config = load_config_file(path).await.unwrap();
+
+        assert_eq!(config.extends, vec!["shared/base.yaml".to_string()]);
+        assert_eq!(config.ignores, vec!["^WIP".to_string()]);
+        assert!(config.rules.body_empty.is_none());
+
+        let violations = config.validate(&Message::new("feat(web): add new page".to_string()));
+        let violation = violations
+            .iter()
+            .find(|violation| violation.rule == "scope-enum")
+            .unwrap();
+        assert_eq!(violation.level, Level::Warning);
+        assert_eq!(
+            violation.message,
+            "scope web is not allowed. Only [\"cli\"] are allowed".to_string()
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_load_config_file_with_cyclic_extends() {
+        let root = temp_dir("cyclic-extends");
+        fs::write(root.join("a.yaml"), "extends:\n  - b.yaml\nrules: {}\n").unwrap();
+        fs::write(root.join("b.yaml"), "extends:\n  - a.yaml\nrules: {}\n").unwrap();
+
+        let err = load_config_file(root.join("a.yaml")).await.unwrap_err();
+        assert!(err.starts_with("Cyclic extends: "), "{}", err);
+        assert!(err.ends_with("a.yaml"), "{}", err);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_subject_case_default_allowed() {
+        let config: Config = serde_yaml::from_str(
+            "rules:
+  subject-case:
+    level: error
+",
+        )
+        .unwrap();
+
+        let violations = config.validate(&Message::new("feat: Add new flag".to_string()));
+        let rules: Vec<&str> = violations.iter().map(|v| v.rule.as_str()).collect();
+        assert_eq!(rules, vec!["subject-case"]);
+    }
+
+    #[test]
+    fn test_subject_case_unknown_case() {
+        let result: Result<Config, _> = serde_yaml::from_str(
+            "rules:
+  subject-case:
+    level: error
+    allowed:
+      - lower-cas
+",
+        );
+
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("unknown case \"lower-cas\""), "{}", err);
+    }
+
+    #[test]
+    fn test_type_case_unknown_case() {
+        let result: Result<Config, _> = serde_yaml::from_str(
+            "rules:
+  type-case:
+    level: error
+    allowed:
+      - lowr-case
+",
+        );
+
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("unknown case \"lowr-case\""), "{}", err);
+    }
+
+    #[test]
+    fn test_scope_case_unknown_case() {
+        let result: Result<Config, _> = serde_yaml::from_str(
+            "rules:
+  scope-case:
+    level: error
+    allowed:
+      - kebabcase
+",
+        );
+
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("unknown case \"kebabcase\""), "{}", err);
+    }
+
+    #[test]
+    fn test_footer_case_unknown_case() {
+        let result: Result<Config, _> = serde_yaml::from_str(
+            "rules:
+  footer-case:
+    level: error
+    allowed:
+      - upper
+",
+        );
+
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("unknown case \"upper\""), "{}", err);
+    }
+
+    #[test]
+    fn test_validate_ignore_revert() {
+        let config: Config = serde_yaml::from_str(
+            "rules:
+  scope-empty:
+    level: error
+  type-empty:
+    level: error
+ignore-revert:
+  - type-empty
+default-ignores: false
+",
+        )
+        .unwrap();
+
+        let violations = config.validate(&Message::new(
+            "Revert \"feat(cli): add new flag\"
+
+This reverts commit 0123456789abcdef0123456789abcdef01234567."
+                .to_string(),
+        ));
+        let rules: Vec<&str> = violations.iter().map(|v| v.rule.as_str()).collect();
+        assert_eq!(rules, vec!["scope-empty"]);
+
+        let violations = config.validate(&Message::new("revert add new flag".to_string()));
+        let rules: Vec<&str> = violations.iter().map(|v| v.rule.as_str()).collect();
+        assert_eq!(rules, vec!["scope-empty", "type-empty"]);
+    }
+
+    #[test]
+    fn test_validate_severity() {
+        let config: Config = serde_yaml::from_str(
+            "rules:
+  scope-empty:
+    level: error
+  type-empty:
+    level: error
+  body-empty:
+    level: error
+severity:
+  scope-empty: warning
+  body-empty: off
+",
+        )
+        .unwrap();
+
+        let violations = config.validate(&Message::new("add new flag".to_string()));
+        let levels: Vec<(&str, Level)> = violations
+            .iter()
+            .map(|v| (v.rule.as_str(), v.level))
+            .collect();
+        assert_eq!(
+            levels,
+            vec![
+                ("scope-empty", Level::Warning),
+                ("type-empty", Level::Error)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_ignore_merge() {
+        let config: Config = serde_yaml::from_str(
+            "rules:
+  type-empty:
+    level: error
+ignore-merge: true
+default-ignores: false
+",
+        )
+        .unwrap();
+
+        let message = Message::new("Merge branch 'main' into feature".to_string());
+        assert!(config.validate(&message).is_empty());
+
+        let violations = config.validate(&Message::new("add new flag".to_string()));
+        let rules: Vec<&str> = violations.iter().map(|v| v.rule.as_str()).collect();
+        assert_eq!(rules, vec!["type-empty"]);
     }
-}
 
-/// Try to load configuration file from the specified path.
-/// First try to load it as JSON, then as YAML.
-/// If both fail, return an error.
-async fn load_unknown_config_file(path: PathBuf) -> Result<Config, String> {
-    let text = fs::read_to_string(path.clone()).unwrap();
+    #[test]
+    fn test_validate_merge_prefixes() {
+        let config: Config = serde_yaml::from_str(
+            "rules:
+  type-empty:
+    level: error
+ignore-merge: true
+merge-prefixes:
+  - Merge pull request
+default-ignores: false
+",
+        )
+        .unwrap();
 
-    if let Ok(config) = serde_json::from_str::<Config>(&text) {
-        return Ok(config);
+        let message = Message::new("Merge pull request #1 from keke/feature".to_string());
+        assert!(config.validate(&message).is_empty());
+
+        let message = Message::new("Merge branch 'main' into feature".to_string());
+        assert!(!config.validate(&message).is_empty());
+    }
+
+    #[test]
+    fn test_validate_ignores() {
+        let config: Config = serde_yaml::from_str(
+            "rules:
+  type-empty:
+    level: error
+ignores:
+  - ^Bump \\S+ from \\S+ to \\S+
+",
+        )
+        .unwrap();
+
+        let message = Message::new("Bump serde from 1.0.0 to 1.0.1".to_string());
+        assert!(config.validate(&message).is_empty());
+
+        let violations = config.validate(&Message::new("add new flag".to_string()));
+        let rules: Vec<&str> = violations.iter().map(|v| v.rule.as_str()).collect();
+        assert_eq!(rules, vec!["type-empty"]);
     }
 
-    if let Ok(config) = serde_yaml::from_str::<Config>(&text) {
-        return Ok(config);
+    #[test]
+    fn test_validate_invalid_ignores() {
+        let config = Config {
+            ignores: vec!["(".to_string()],
+            ..Default::default()
+        };
+
+        let violations = config.validate(&Message::new("feat: add new flag".to_string()));
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].rule, "ignores".to_string());
+        assert_eq!(violations[0].level, Level::Error);
+    }
+
+    #[test]
+    fn test_validate_default_ignores() {
+        let message = Message::new("Merge branch 'main' into feature".to_string());
+
+        let config = Config::default();
+        assert!(config.validate(&message).is_empty());
+
+        let config = Config {
+            default_ignores: false,
+            ..Default::default()
+        };
+        let rules: Vec<String> = config
+            .validate(&message)
+            .into_iter()
+            .map(|v| v.rule)
+            .collect();
+        assert_eq!(rules, vec!["type-empty"]);
     }
 
-    Err(format!(
-        "Failed to parse configuration file: {}",
-        path.display()
-    ))
+    #[test]
+    fn test_default_ignores_with_no_fixup() {
+        let config: Config = serde_yaml::from_str(
+            "rules:
+  no-fixup:
+    level: error
+",
+        )
+        .unwrap();
+
+        let violations = config.validate(&Message::new("fixup! feat: add x".to_string()));
+        let rules: Vec<&str> = violations.iter().map(|v| v.rule.as_str()).collect();
+        assert!(rules.contains(&"no-fixup"), "{:?}", rules);
+    }
+
+    #[test]
+    fn test_default_ignores_with_revert_hash() {
+        let config: Config = serde_yaml::from_str(
+            "rules:
+  revert-hash:
+    level: error
+ignore-revert:
+  - type-empty
+",
+        )
+        .unwrap();
+
+        let violations = config.validate(&Message::new(
+            "Revert \"feat(cli): add new flag\"".to_string(),
+        ));
+        let rules: Vec<&str> = violations.iter().map(|v| v.rule.as_str()).collect();
+        assert_eq!(rules, vec!["revert-hash"]);
+    }
+
+    #[test]
+    fn test_default_ignores_with_revert_subject_match() {
+        let config: Config = serde_yaml::from_str(
+            "rules:
+  revert-subject-match:
+    level: error
+ignore-revert:
+  - type-empty
+",
+        )
+        .unwrap();
+
+        let violations = config.validate(&Message::new(
+            "Revert \"\"
+
+This reverts commit 0123456789abcdef0123456789abcdef01234567."
+                .to_string(),
+        ));
+        let rules: Vec<&str> = violations.iter().map(|v| v.rule.as_str()).collect();
+        assert_eq!(rules, vec!["revert-subject-match"]);
+    }
+
+    #[test]
+    fn test_default_ignores_with_ignore_revert() {
+        let config: Config = serde_yaml::from_str(
+            "rules:
+  scope-empty:
+    level: error
+ignore-revert:
+  - type-empty
+",
+        )
+        .unwrap();
+
+        let violations = config.validate(&Message::new(
+            "Revert \"feat(cli): add new flag\"".to_string(),
+        ));
+        let rules: Vec<&str> = violations.iter().map(|v| v.rule.as_str()).collect();
+        assert_eq!(rules, vec!["scope-empty"]);
+    }
+
+    #[test]
+    fn test_default_ignores() {
+        let config = Config::default();
+        for raw in [
+            "Revert \"feat(cli): add new flag\"",
+            "fixup! feat(cli): add new flag",
+            "v1.2.3",
+            "1.2.3-rc.1\n\nSigned-off-by: Keke <keke@example.com>",
+            "chore(release): 1.2.3",
+        ] {
+            let message = Message::new(raw.to_string());
+            assert!(config.is_ignored(&message).unwrap(), "{}", raw);
+        }
+
+        let message = Message::new("v1.2.3 is released".to_string());
+        assert!(!config.is_ignored(&message).unwrap());
+    }
 }
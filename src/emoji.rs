@@ -0,0 +1,77 @@
+/// Ranges of the emoji characters.
+/// See: https://www.unicode.org/reports/tr51/
+const EMOJI: [(u32, u32); 9] = [
+    (0x203C, 0x2049),   // Double exclamation mark and exclamation question mark
+    (0x2300, 0x23FF),   // Miscellaneous Technical (e.g. ⌚ and ⏰)
+    (0x2600, 0x27BF),   // Miscellaneous Symbols and Dingbats
+    (0x2B00, 0x2BFF),   // Miscellaneous Symbols and Arrows (e.g. ⭐)
+    (0x1F000, 0x1F2FF), // Mahjong Tiles, Playing Cards and Enclosed Alphanumerics
+    (0x1F300, 0x1F5FF), // Miscellaneous Symbols and Pictographs
+    (0x1F600, 0x1F6FF), // Emoticons and Transport and Map Symbols
+    (0x1F900, 0x1F9FF), // Supplemental Symbols and Pictographs
+    (0x1FA70, 0x1FAFF), // Symbols and Pictographs Extended-A
+];
+
+/// Characters which are part of an emoji sequence but not emoji by themselves.
+const JOINERS: [(u32, u32); 3] = [
+    (0x200D, 0x200D),   // Zero width joiner
+    (0xFE0E, 0xFE0F),   // Variation Selectors for text and emoji presentation
+    (0x1F3FB, 0x1F3FF), // Emoji Modifiers (skin tones)
+];
+
+/// Check whether the character is in one of the ranges.
+fn in_ranges(c: char, ranges: &[(u32, u32)]) -> bool {
+    let c = c as u32;
+    ranges.iter().any(|(start, end)| *start <= c && c <= *end)
+}
+
+/// Check whether the character is an emoji.
+pub fn is_emoji(c: char) -> bool {
+    in_ranges(c, &EMOJI)
+}
+
+/// Find the emoji in the text.
+///
+/// The consecutive emoji characters joined by the zero width joiner or followed
+/// by the variation selector or the skin tone (e.g. ♻️, 👍🏽 and 👨‍💻) are returned as a single emoji.
+pub fn find(text: &str) -> Vec<String> {
+    let mut found: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut joined = false;
+
+    for c in text.chars() {
+        if in_ranges(c, &JOINERS) && !current.is_empty() {
+            current.push(c);
+            joined = c == '\u{200D}';
+        } else if is_emoji(c) {
+            if !current.is_empty() && !joined {
+                found.push(std::mem::take(&mut current));
+            }
+            current.push(c);
+            joined = false;
+        } else if !current.is_empty() {
+            found.push(std::mem::take(&mut current));
+            joined = false;
+        }
+    }
+
+    if !current.is_empty() {
+        found.push(current);
+    }
+
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find("add new flag"), Vec::<String>::new());
+        assert_eq!(find("🎉 add new flag"), vec!["🎉"]);
+        assert_eq!(find("♻️ refactor 🔥🐛"), vec!["♻️", "🔥", "🐛"]);
+        assert_eq!(find("👨‍💻 add new flag"), vec!["👨‍💻"]);
+        assert_eq!(find("👍🏽 add new flag"), vec!["👍🏽"]);
+    }
+}
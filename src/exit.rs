@@ -0,0 +1,28 @@
+use std::process;
+
+/// ExitCode represents the exit status of the CLI.
+///
+/// The codes are distinct, so that the scripts and the CI can tell
+/// the failed lint from the broken setup.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ExitCode {
+    /// No error-level violation is found.
+    Success = 0,
+
+    /// Error-level violations are found, or warning-level ones in the strict mode.
+    Failure = 1,
+
+    /// The config file or the commit messages cannot be read or parsed.
+    Config = 2,
+
+    /// The command line arguments are invalid, e.g. the file given by --edit cannot be read,
+    /// the messages fixed by --fix cannot be written, or the range given by --range is unknown to git.
+    Usage = 3,
+}
+
+impl ExitCode {
+    /// Exit the process with the code.
+    pub fn exit(self) -> ! {
+        process::exit(self as i32)
+    }
+}
@@ -1,5 +1,7 @@
-use regex::Regex;
-use std::{collections::HashMap, process::Command};
+use std::process::Command;
+
+use crate::{message::Footer, pattern};
+
 /// ReadCommitMessageOptions represents the options for reading commit messages.
 /// Transparently, it is defined to be similar to the behavior of the git log command.
 #[derive(Clone, Debug)]
@@ -47,8 +49,143 @@ pub fn read(options: ReadCommitMessageOptions) -> Vec<String> {
     extract_commit_messages(&stdout)
 }
 
+/// PushRange represents the range of the commits pushed such as `<old-sha>..<new-sha>`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PushRange {
+    /// Old is the commit the ref pointed to before the push.
+    /// It is `None` for the initial push, where git gives the all zeros SHA.
+    pub old: Option<String>,
+
+    /// New is the commit the ref points to after the push.
+    pub new: String,
+}
+
+impl PushRange {
+    /// Parse the range from `<old-sha>..<new-sha>`.
+    pub fn parse(range: &str) -> Result<Self, String> {
+        let (old, new) = range
+            .split_once("..")
+            .filter(|(old, new)| !old.is_empty() && !new.is_empty() && !new.starts_with('.'))
+            .ok_or_else(|| format!("invalid range {:?}, expected <old-sha>..<new-sha>", range))?;
+
+        Ok(Self {
+            old: Some(old.to_string()).filter(|old| !old.chars().all(|c| c == '0')),
+            new: new.to_string(),
+        })
+    }
+
+    /// Revision range of the new commits for git log.
+    /// For the initial push, the commits already in any remote-tracking branch are excluded
+    /// as described in the `pre-push` hook of the githooks documentation.
+    ///
+    /// See: https://git-scm.com/docs/githooks#_pre_push
+    fn revisions(&self) -> Vec<String> {
+        match &self.old {
+            Some(old) => vec![format!("{}..{}", old, self.new)],
+            None => vec![
+                self.new.clone(),
+                "--not".to_string(),
+                "--remotes".to_string(),
+            ],
+        }
+    }
+}
+
+/// Commit represents a commit message with its SHA.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Commit {
+    /// SHA of the commit.
+    pub sha: String,
+
+    /// Message of the commit.
+    pub message: String,
+}
+
+/// Get the commits of the push range from git in the order they were made.
+/// Returns the error of git if the range cannot be read, e.g. the SHAs are unknown.
+pub fn read_push_range(range: &PushRange, path: &str) -> Result<Vec<Commit>, String> {
+    let output = Command::new("git")
+        .arg("log")
+        .arg("--format=%H%x00%B%x00")
+        .arg("--no-merges")
+        .arg("--no-decorate")
+        .arg("--reverse")
+        .args(range.revisions())
+        .arg("--")
+        .arg(path)
+        .output()
+        .map_err(|err| format!("failed to execute git log: {}", err))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "git log failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    Ok(parse_commits(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Parse the commits from the output of `git log --format=%H%x00%B%x00`,
+/// where the SHA and the message of each commit are terminated by a NUL byte.
+pub fn parse_commits(input: &str) -> Vec<Commit> {
+    let fields: Vec<&str> = input.split('\0').collect();
+
+    fields
+        .chunks_exact(2)
+        .map(|commit| Commit {
+            sha: commit[0].trim().to_string(),
+            message: commit[1].trim_matches('\n').to_string(),
+        })
+        .filter(|commit| !commit.sha.is_empty())
+        .collect()
+}
+
+/// Strip the comment lines and the diff appended by `git commit --verbose`
+/// in the same way as git does when it reads the edited commit message.
+///
+/// See: https://git-scm.com/docs/git-commit#Documentation/git-commit.txt---cleanupltmodegt
+pub fn strip_comments(raw: &str, comment_char: char) -> String {
+    let scissors = format!(
+        "{} ------------------------ >8 ------------------------",
+        comment_char
+    );
+
+    let mut lines = Vec::new();
+    for line in raw.lines() {
+        // Everything below the scissors line is the diff of the verbose mode.
+        if line == scissors {
+            break;
+        }
+
+        if !line.starts_with(comment_char) {
+            lines.push(line);
+        }
+    }
+
+    let mut message = lines.join("\n");
+    if raw.ends_with('\n') && !message.is_empty() {
+        message.push('\n');
+    }
+
+    message
+}
+
+/// Split the commit messages separated by a NUL byte.
+///
+/// This matches the output of `git log --format=%B%x00`, where each message
+/// except the first one starts with the newline added between the commits.
+pub fn split_commit_messages(input: &str) -> Vec<String> {
+    input
+        .split('\0')
+        .map(|message| message.trim_start_matches('\n'))
+        .filter(|message| !message.trim().is_empty())
+        .map(|message| message.to_string())
+        .collect()
+}
+
 fn extract_commit_messages(input: &str) -> Vec<String> {
-    let commit_delimiter = Regex::new(r"(?m)^commit [0-9a-f]{40}$").unwrap();
+    let commit_delimiter = pattern::compile(r"(?m)^commit [0-9a-f]{40}$").unwrap();
     let commits: Vec<&str> = commit_delimiter.split(input).collect();
 
     let mut messages: Vec<String> = Vec::new();
@@ -67,61 +204,121 @@ fn extract_commit_messages(input: &str) -> Vec<String> {
 /// Please refer the official documentation for the commit message format.
 /// See: https://www.conventionalcommits.org/en/v1.0.0/#summary
 ///
-/// ```
+/// ```text
 /// <type>[optional scope]: <description> <-- Subject
 ///
 /// [optional body] <-- Body
 ///
 /// [optional footer(s)] <-- Footer
 /// ```
-pub fn parse_commit_message(
-    message: &str,
-) -> (String, Option<String>, Option<HashMap<String, String>>) {
+///
+/// Note that the footers are the last paragraph of the message whose first line
+/// is a footer (e.g. `Refs: #123`). Otherwise, the paragraph is part of the body.
+pub fn parse_commit_message(message: &str) -> (String, Option<String>, Option<Vec<Footer>>) {
     let lines: Vec<&str> = message.lines().collect();
-    let mut lines_iter = lines.iter();
 
-    let subject = lines_iter.next().unwrap_or(&"").trim().to_string();
-    let mut body = None;
-    let mut footer = None;
+    let subject = lines.first().unwrap_or(&"").trim().to_string();
+    let rest = lines.get(1..).unwrap_or_default();
+
+    // The last paragraph starts after the last blank line.
+    let last_paragraph = rest
+        .iter()
+        .rposition(|line| line.trim().is_empty())
+        .map_or(0, |i| i + 1);
+
+    let footer_start = match rest.get(last_paragraph) {
+        Some(line) if parse_footer_line(line).is_some() => last_paragraph,
+        _ => rest.len(),
+    };
+
+    let body_lines: Vec<&str> = rest[..footer_start]
+        .iter()
+        .map(|line| line.trim())
+        .collect();
+    let body_start = body_lines.iter().position(|line| !line.is_empty());
+    let body_end = body_lines.iter().rposition(|line| !line.is_empty());
+    let body = match (body_start, body_end) {
+        (Some(start), Some(end)) => Some(body_lines[start..=end].join("\n")),
+        _ => None,
+    };
+
+    let footers = parse_footers(&rest[footer_start..]);
 
-    let mut in_body = false;
-    let mut in_footer = false;
+    (subject, body, footers)
+}
 
-    for line in lines_iter {
-        if line.trim().is_empty() {
-            if in_body {
-                in_body = false;
-                in_footer = true;
+/// Parse the footer lines and return the footers.
+///
+/// A line which is not a footer continues the value of the previous footer
+/// so that multi-line values (e.g. indented `BREAKING CHANGE` descriptions) are supported.
+fn parse_footers(lines: &[&str]) -> Option<Vec<Footer>> {
+    let mut footers: Vec<Footer> = Vec::new();
+
+    for line in lines {
+        if let Some(footer) = parse_footer_line(line) {
+            footers.push(footer);
+        } else if let Some(footer) = footers.last_mut() {
+            if line.trim().is_empty() {
+                continue;
             }
-        } else if in_footer {
-            let parts: Vec<&str> = line.splitn(2, ':').map(|part| part.trim()).collect();
-            if parts.len() == 2 {
-                let key = parts[0].to_string();
-                let value = parts[1].to_string();
-                let footer_map = footer.get_or_insert(HashMap::new());
-                footer_map.insert(key, value);
+            if !footer.value.is_empty() {
+                footer.value.push('\n');
             }
-        } else if !in_body {
-            in_body = true;
-            body = Some(line.trim().to_string());
-        } else if let Some(b) = body.as_mut() {
-            b.push('\n');
-            b.push_str(line.trim());
+            footer.value.push_str(line.trim());
         }
     }
 
-    (subject, body, footer)
+    if footers.is_empty() {
+        return None;
+    }
+
+    Some(footers)
+}
+
+/// Parse a single footer line such as `token: value`, `token #value` or `BREAKING CHANGE: value`.
+///
+/// See: https://www.conventionalcommits.org/en/v1.0.0/#specification
+pub(crate) fn parse_footer_line(line: &str) -> Option<Footer> {
+    let re = pattern::compile(
+        r"^(?P<token>BREAKING CHANGE|[\w-]+)(?:(?P<colon>:)(?:\s+|$)|\s+#)(?P<value>.*)$",
+    )
+    .unwrap();
+
+    let captures = re.captures(line.trim())?;
+    let separator = match captures.name("colon") {
+        Some(_) => ':',
+        None => '#',
+    };
+
+    Some(Footer {
+        token: captures["token"].to_string(),
+        value: captures["value"].trim().to_string(),
+        separator,
+    })
 }
 
+/// Default separator between the `type(scope)` prefix and the description.
+pub const DEFAULT_SEPARATOR: &str = ":";
+
 /// Parse a commit message subject and return the type, scope, and description.
 ///
 /// Note that exclamation mark is not respected as the existing commitlint
 /// does not have any rules for it.
 /// See: https://commitlint.js.org/#/reference-rules
 pub fn parse_subject(subject: &str) -> (Option<String>, Option<String>, Option<String>) {
-    let re = regex::Regex::new(
-        r"^(?P<type>\w+)(?:\((?P<scope>[^\)]+)\))?(?:!)?\:\s?(?P<description>.*)$",
-    )
+    parse_subject_with_separator(subject, DEFAULT_SEPARATOR)
+}
+
+/// Parse a commit message subject whose prefix and description are separated
+/// by the given separator such as `/` of `feat(cli)/ add dummy option`.
+pub fn parse_subject_with_separator(
+    subject: &str,
+    separator: &str,
+) -> (Option<String>, Option<String>, Option<String>) {
+    let re = pattern::compile(&format!(
+        r"^(?P<type>\w+)(?:\((?P<scope>[^\)]*)\))?(?:!)?{}\s?(?P<description>.*)$",
+        regex::escape(separator)
+    ))
     .unwrap();
     if let Some(captures) = re.captures(subject) {
         let r#type = captures.name("type").map(|m| m.as_str().to_string());
@@ -138,6 +335,122 @@ pub fn parse_subject(subject: &str) -> (Option<String>, Option<String>, Option<S
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_strip_comments() {
+        let input = "feat(cli): add new flag
+
+Hello world
+# Please enter the commit message for your changes. Lines starting
+# with '#' will be ignored, and an empty message aborts the commit.
+#
+# On branch main
+# ------------------------ >8 ------------------------
+# Do not modify or remove the line above.
+# Everything below it will be ignored.
+diff --git a/src/main.rs b/src/main.rs
+index 0123456..789abcd 100644
++fn main() {}
+";
+        let stripped = strip_comments(input, '#');
+        assert_eq!(stripped, "feat(cli): add new flag\n\nHello world\n");
+
+        let (subject, body, footer) = parse_commit_message(&stripped);
+        assert_eq!(subject, "feat(cli): add new flag");
+        assert_eq!(body, Some("Hello world".to_string()));
+        assert_eq!(footer, None);
+    }
+
+    #[test]
+    fn test_strip_comments_keeps_quoted_diff() {
+        let input = "fix(git): handle renamed files
+
+The following diff was rejected:
+diff --git a/src/main.rs b/src/main.rs
+# On branch main
+";
+        assert_eq!(
+            strip_comments(input, '#'),
+            "fix(git): handle renamed files
+
+The following diff was rejected:
+diff --git a/src/main.rs b/src/main.rs
+"
+        );
+    }
+
+    #[test]
+    fn test_strip_comments_with_custom_comment_char() {
+        let input = "feat(cli): add new flag
+
+#1 is fixed
+; On branch main";
+        assert_eq!(
+            strip_comments(input, ';'),
+            "feat(cli): add new flag\n\n#1 is fixed"
+        );
+    }
+
+    #[test]
+    fn test_parse_push_range() {
+        assert_eq!(
+            PushRange::parse("1a2b3c..4d5e6f"),
+            Ok(PushRange {
+                old: Some("1a2b3c".to_string()),
+                new: "4d5e6f".to_string(),
+            })
+        );
+        assert_eq!(
+            PushRange::parse(&format!("{}..4d5e6f", "0".repeat(40))),
+            Ok(PushRange {
+                old: None,
+                new: "4d5e6f".to_string(),
+            })
+        );
+        assert!(PushRange::parse("4d5e6f").is_err());
+        assert!(PushRange::parse("1a2b3c...4d5e6f").is_err());
+    }
+
+    #[test]
+    fn test_push_range_revisions() {
+        let range = PushRange::parse("1a2b3c..4d5e6f").unwrap();
+        assert_eq!(range.revisions(), vec!["1a2b3c..4d5e6f"]);
+
+        let range = PushRange::parse(&format!("{}..4d5e6f", "0".repeat(40))).unwrap();
+        assert_eq!(range.revisions(), vec!["4d5e6f", "--not", "--remotes"]);
+    }
+
+    #[test]
+    fn test_parse_commits() {
+        let input = "1a2b3c\0feat(cli): add new flag\n\nHello\n\0\n4d5e6f\0update\n\0\n";
+
+        assert_eq!(
+            parse_commits(input),
+            vec![
+                Commit {
+                    sha: "1a2b3c".to_string(),
+                    message: "feat(cli): add new flag\n\nHello".to_string(),
+                },
+                Commit {
+                    sha: "4d5e6f".to_string(),
+                    message: "update".to_string(),
+                },
+            ]
+        );
+        assert!(parse_commits("").is_empty());
+    }
+
+    #[test]
+    fn test_split_commit_messages() {
+        let input = "feat(cli): add new flag\n\nHello world\n\0\nfix: typo\n\0\n";
+        assert_eq!(
+            split_commit_messages(input),
+            vec![
+                "feat(cli): add new flag\n\nHello world\n".to_string(),
+                "fix: typo\n".to_string(),
+            ]
+        );
+    }
+
     #[test]
     fn test_single_line_parse_commit_message() {
         let input = "feat(cli): add dummy option";
@@ -167,12 +480,16 @@ Hello, there!
 Link: Hello";
         let (subject, body, footer) = parse_commit_message(input);
 
-        let mut f = HashMap::new();
-        f.insert("Link".to_string(), "Hello".to_string());
         assert_eq!(subject, "feat(cli): add dummy option");
         assert_eq!(body, Some("Hello, there!".to_string()));
-        assert!(footer.is_some());
-        assert_eq!(f.get("Link"), Some(&"Hello".to_string()));
+        assert_eq!(
+            footer,
+            Some(vec![Footer {
+                token: "Link".to_string(),
+                value: "Hello".to_string(),
+                separator: ':',
+            }])
+        );
     }
 
     #[test]
@@ -188,11 +505,88 @@ Name: Keke";
         assert_eq!(subject, "feat(cli): add dummy option");
         assert_eq!(body, Some("Hello, there!".to_string()));
         assert!(footer.is_some());
+        let footer = footer.unwrap();
+        assert_eq!(footer.len(), 2);
+        assert_eq!(footer[0].token, "Link".to_string());
+        assert_eq!(footer[0].value, "Hello".to_string());
+        assert_eq!(footer[1].token, "Name".to_string());
+        assert_eq!(footer[1].value, "Keke".to_string());
+    }
+
+    #[test]
+    fn test_conventional_footers_parse_commit_message() {
+        let input = "feat(cli)!: drop deprecated option
+
+Hello, there!
+
+Reviewed-by: Z
+Refs #133
+BREAKING CHANGE: the dummy option is removed,
+  use the new option instead.";
+        let (subject, body, footer) = parse_commit_message(input);
+
+        assert_eq!(subject, "feat(cli)!: drop deprecated option");
+        assert_eq!(body, Some("Hello, there!".to_string()));
         assert_eq!(
-            footer.clone().unwrap().get("Link"),
-            Some(&"Hello".to_string())
+            footer,
+            Some(vec![
+                Footer {
+                    token: "Reviewed-by".to_string(),
+                    value: "Z".to_string(),
+                    separator: ':',
+                },
+                Footer {
+                    token: "Refs".to_string(),
+                    value: "133".to_string(),
+                    separator: '#',
+                },
+                Footer {
+                    token: "BREAKING CHANGE".to_string(),
+                    value: "the dummy option is removed,\nuse the new option instead.".to_string(),
+                    separator: ':',
+                },
+            ])
         );
-        assert_eq!(footer.unwrap().get("Name"), Some(&"Keke".to_string()));
+    }
+
+    #[test]
+    fn test_co_authors_parse_commit_message() {
+        let input = "feat(cli): add dummy option
+
+Co-authored-by: Doe, Jane <jane@example.com>
+Co-authored-by: Keke <Kid> <keke@example.com>";
+        let (_, body, footer) = parse_commit_message(input);
+
+        assert_eq!(body, None);
+
+        let footer = footer.unwrap();
+        assert_eq!(footer.len(), 2);
+        assert!(footer.iter().all(|f| f.is_co_author()));
+        assert_eq!(footer[0].name(), Some("Doe, Jane"));
+        assert_eq!(footer[0].email(), Some("jane@example.com"));
+        assert_eq!(footer[1].name(), Some("Keke <Kid>"));
+        assert_eq!(footer[1].email(), Some("keke@example.com"));
+    }
+
+    #[test]
+    fn test_multiple_paragraphs_body_parse_commit_message() {
+        let input = "feat(cli): add dummy option
+
+Hello, there!
+
+I'm the second paragraph.
+Note that I'm not a footer.";
+        let (subject, body, footer) = parse_commit_message(input);
+
+        assert_eq!(subject, "feat(cli): add dummy option");
+        assert_eq!(
+            body,
+            Some(
+                "Hello, there!\n\nI'm the second paragraph.\nNote that I'm not a footer."
+                    .to_string()
+            )
+        );
+        assert_eq!(footer, None);
     }
 
     #[test]
@@ -247,6 +641,23 @@ Name: Keke";
         );
     }
 
+    #[test]
+    fn test_parse_subject_with_separator() {
+        let input = "feat(cli)/ add dummy option";
+        assert_eq!(
+            parse_subject_with_separator(input, "/"),
+            (
+                Some("feat".to_string()),
+                Some("cli".to_string()),
+                Some("add dummy option".to_string())
+            )
+        );
+        assert_eq!(
+            parse_subject(input),
+            (None, None, Some("feat(cli)/ add dummy option".to_string()))
+        );
+    }
+
     #[test]
     fn test_parse_subject_with_empty_description() {
         let input = "feat(cli): ";
@@ -273,6 +684,19 @@ Name: Keke";
         );
     }
 
+    #[test]
+    fn test_parse_subject_with_empty_parentheses() {
+        let input = "feat(): add dummy commit";
+        assert_eq!(
+            parse_subject(input),
+            (
+                Some("feat".to_string()),
+                Some("".to_string()),
+                Some("add dummy commit".to_string())
+            )
+        );
+    }
+
     #[test]
     fn test_parse_subject_without_message() {
         let input = "";
@@ -0,0 +1,83 @@
+//! Lint commit messages with [Conventional Commits](https://www.conventionalcommits.org).
+//!
+//! The CLI is built on top of this library, so that editor plugins and other
+//! integrations can embed the linter without shelling out.
+mod case;
+pub mod config;
+mod emoji;
+pub mod git;
+pub mod message;
+pub mod output;
+mod pattern;
+pub mod registry;
+pub mod result;
+pub mod rule;
+pub mod trace;
+mod width;
+
+use config::Config;
+use message::Message;
+use result::{LintResult, Violation};
+
+use std::thread;
+
+/// Lint the raw commit message with the rules of the configuration.
+///
+/// All the violations are collected instead of stopping at the first one,
+/// and classified into the errors and the warnings by `LintResult::from`.
+/// Each of them is sorted by the rule name in ascending order.
+pub fn lint(raw: &str, config: &Config) -> LintResult {
+    let message = Message::with_separator(raw.to_string(), &config.separator);
+
+    let mut violations = config.validate(&message);
+    violations.sort_by(|a, b| a.rule.cmp(&b.rule));
+
+    LintResult::from(violations)
+}
+
+/// Lint the commit messages in parallel with the rules of the configuration.
+///
+/// The messages are split into chunks linted by at most `threads` threads.
+/// The violations are returned per message in the order of the messages, and
+/// sorted by the rule name in ascending order as in `lint`, so that the result
+/// is the same regardless of the number of threads.
+/// Unlike `lint`, the violations are not classified, so that they can be
+/// passed to the output formats as they are.
+pub fn lint_batch(messages: &[Message], config: &Config, threads: usize) -> Vec<Vec<Violation>> {
+    let lint_message = |message: &Message| {
+        let mut violations = config.validate(message);
+        violations.sort_by(|a, b| a.rule.cmp(&b.rule));
+        violations
+    };
+
+    let threads = threads.clamp(1, messages.len().max(1));
+    if threads == 1 {
+        return messages.iter().map(lint_message).collect();
+    }
+
+    let chunk_size = messages.len().div_ceil(threads);
+    thread::scope(|scope| {
+        let handles: Vec<_> = messages
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(move || chunk.iter().map(lint_message).collect::<Vec<_>>()))
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("Failed to lint commit messages"))
+            .collect()
+    })
+}
+
+/// Lint the commit messages together with the batch rules of the configuration,
+/// such as the duplicate subjects across the commits.
+///
+/// The batch rules only run when multiple commit messages are given.
+/// The violations are not bound to a single commit message, and
+/// sorted by the rule name in ascending order as in `lint`.
+pub fn lint_across(messages: &[Message], config: &Config) -> Vec<Violation> {
+    let mut violations = config.validate_batch(messages);
+    violations.sort_by(|a, b| a.rule.cmp(&b.rule));
+
+    violations
+}
@@ -1,75 +1,223 @@
 mod args;
-mod config;
-mod git;
-mod message;
-mod result;
-mod rule;
+mod exit;
 
-use args::Args;
+use args::{Args, Format};
 use clap::Parser;
-use message::validate;
+use commitlint_rs::{
+    config, lint_across, lint_batch, message::Message, output, result, result::Violation, rule,
+    trace,
+};
 
-use std::process::exit;
+use exit::ExitCode;
+
+use std::io::{stderr, stdout};
 
 #[tokio::main]
 async fn main() {
-    let args = Args::parse();
+    let args = match Args::try_parse() {
+        Ok(args) => args,
+        // Help and version are printed to stdout and exit successfully.
+        Err(err) if !err.use_stderr() => err.exit(),
+        Err(err) => {
+            let _ = err.print();
+            ExitCode::Usage.exit()
+        }
+    };
 
     let config = match config::load(args.config.clone()).await {
         Ok(c) => c,
         Err(err) => {
             eprintln!("Failed to load config: {}", err);
-            exit(1)
+            ExitCode::Config.exit()
+        }
+    };
+
+    // The lint results are printed to stderr if stdout is taken by the fixed messages.
+    let out = |text: &str| {
+        if args.prints_fixed() {
+            eprintln!("{}", text)
+        } else {
+            println!("{}", text)
         }
     };
 
     if args.print_config {
-        println!("{}", config);
+        out(&config.to_string());
     }
 
-    let messages = match args.read() {
-        Ok(messages) => messages,
+    let commits = match args.read_push_range() {
+        Ok(commits) => commits,
+        // The range given by --range is malformed or unknown to git.
         Err(err) => {
             eprintln!("Failed to read commit messages: {}", err);
-            exit(1)
+            ExitCode::Usage.exit()
         }
     };
+    // The SHAs are only known for the commits of the push range.
+    let shas: Option<Vec<String>> = commits
+        .as_ref()
+        .map(|commits| commits.iter().map(|commit| commit.sha.clone()).collect());
 
-    let threads = messages
-        .into_iter()
-        .map(|message| {
-            let config = config.clone();
-            tokio::spawn(async move { validate(&message, &config).await })
-        })
-        .collect::<Vec<_>>();
+    let mut messages = match commits {
+        Some(commits) => commits
+            .into_iter()
+            .map(|commit| Message::with_separator(commit.message, &config.separator))
+            .collect(),
+        None => match args.read(config.comment_char, &config.separator) {
+            Ok(messages) => messages,
+            // The only failure is the unreadable file given by --edit.
+            Err(err) => {
+                eprintln!("Failed to read commit messages: {}", err);
+                ExitCode::Usage.exit()
+            }
+        },
+    };
 
-    let results = futures::future::join_all(threads).await;
+    if args.fix {
+        for message in messages.iter_mut() {
+            config.rules.fix(message);
+        }
+
+        if let Err(err) = args.write(&messages) {
+            eprintln!("Failed to write fixed commit messages: {}", err);
+            ExitCode::Usage.exit()
+        }
+    }
 
-    let mut has_error: bool = false;
-    for result in &results {
-        if let Err(err) = result {
-            eprintln!("{}", err);
+    if args.debug {
+        for (index, message) in messages.iter().enumerate() {
+            eprintln!("debug: commit {}", index + 1);
+            for trace in trace::trace(message, &config) {
+                eprintln!("debug:   {}", trace);
+            }
         }
 
-        if let Ok(Ok(h)) = result {
-            if !h.violations.is_empty() {
-                for violation in &h.violations {
+        // The batch rules only run when multiple commit messages are given.
+        if messages.len() > 1 {
+            eprintln!("debug: batch");
+            for trace in trace::trace_batch(&messages, &config) {
+                eprintln!("debug:   {}", trace);
+            }
+        }
+    }
+
+    // Violations are kept per commit message so that they can be grouped in the batch mode.
+    let reports = lint_batch(&messages, &config, args.threads());
+    // Violations across the commit messages are not bound to a single commit.
+    let batch_violations = lint_across(&messages, &config);
+
+    let violations = [reports.concat(), batch_violations.clone()].concat();
+    let has_failure = result::has_failure(&violations, args.strict || config.strict);
+
+    match args.format {
+        // The exit status is kept even if the output is suppressed.
+        _ if args.quiet => {}
+        Format::Text => {
+            let stderr_color = args.color(&stderr());
+            let stdout_color = if args.prints_fixed() {
+                stderr_color
+            } else {
+                args.color(&stdout())
+            };
+
+            // Each violation is prefixed with the commit it belongs to.
+            let entries: Vec<(String, &Violation)> = reports
+                .iter()
+                .enumerate()
+                .flat_map(|(index, violations)| {
+                    let prefix = match &shas {
+                        Some(shas) => {
+                            format!("commit {}: ", &shas[index][..shas[index].len().min(7)])
+                        }
+                        None if args.batch => format!("commit {}: ", index + 1),
+                        None => String::new(),
+                    };
+                    violations
+                        .iter()
+                        .map(move |violation| (prefix.clone(), violation))
+                })
+                .chain(
+                    batch_violations
+                        .iter()
+                        .map(|violation| (String::new(), violation)),
+                )
+                .collect();
+
+            if args.group_by_level {
+                if let Some(errors) = output::group(rule::Level::Error, &entries, stderr_color) {
+                    eprintln!("{}", errors)
+                }
+                if let Some(warnings) = output::group(rule::Level::Warning, &entries, stdout_color)
+                {
+                    out(&warnings)
+                }
+            } else {
+                for (prefix, violation) in &entries {
                     match violation.level {
                         rule::Level::Error => {
-                            eprintln!("{}", violation.message);
-                            has_error = true
-                        }
-                        rule::Level::Warning => {
-                            println!("{}", violation.message);
+                            eprintln!("{}{}", prefix, output::text(violation, stderr_color))
                         }
+                        rule::Level::Warning => out(&format!(
+                            "{}{}",
+                            prefix,
+                            output::text(violation, stdout_color)
+                        )),
                         _ => {}
                     }
                 }
             }
+
+            match output::summary(&violations) {
+                Some(summary) => out(&summary),
+                None if args.verbose => out("✔ no problems found"),
+                None => {}
+            }
+        }
+        Format::Json if shas.is_some() => out(&output::json_commits(
+            shas.as_deref().unwrap(),
+            &reports,
+            &batch_violations,
+        )),
+        Format::Json if args.batch => out(&output::json_batch(&reports, &batch_violations)),
+        Format::Json => out(&output::json(&violations)),
+        Format::Sarif => {
+            let source = match (&shas, &args.edit) {
+                (Some(shas), _) => output::Source::Commits(shas),
+                (None, Some(path)) => output::Source::File(path),
+                (None, None) => output::Source::Messages,
+            };
+
+            out(&output::sarif(source, &reports, &batch_violations))
+        }
+        Format::Junit => {
+            // The batch rules are reported separately as they only run for multiple commit messages.
+            let batch_rules: Vec<String> = config
+                .rules
+                .batch_rules()
+                .into_iter()
+                .filter(|(_, rule)| rule.is_some() && messages.len() > 1)
+                .map(|(name, _)| name.to_string())
+                .collect();
+            let rules: Vec<String> = config
+                .rules
+                .rules()
+                .into_iter()
+                .filter(|(_, rule)| rule.is_some())
+                .map(|(name, _)| name.to_string())
+                .collect();
+
+            out(&output::junit(
+                &rules,
+                &reports,
+                &batch_rules,
+                &batch_violations,
+            ))
         }
     }
 
-    if has_error {
-        exit(1)
+    if has_failure {
+        ExitCode::Failure.exit()
     }
+
+    ExitCode::Success.exit()
 }
@@ -1,9 +1,5 @@
-use crate::{
-    config::Config,
-    git::{parse_commit_message, parse_subject},
-    result::Result as LintResult,
-};
-use std::{collections::HashMap, fmt::Error};
+use crate::git::{parse_commit_message, parse_subject_with_separator, DEFAULT_SEPARATOR};
+use std::fmt;
 
 /// Message represents a single commit message.
 ///
@@ -25,7 +21,7 @@ pub struct Message {
     pub description: Option<String>,
 
     /// Footers part of the commit message.
-    pub footers: Option<HashMap<String, String>>,
+    pub footers: Option<Vec<Footer>>,
 
     /// Raw commit message (or any input from stdin) including the body and footers.
     pub raw: String,
@@ -36,16 +32,108 @@ pub struct Message {
     /// Scope part of the commit message.
     pub scope: Option<String>,
 
+    /// Separator between the `type(scope)` prefix and the description such as `:`.
+    /// It is kept so that the fixed message is parsed in the same way.
+    pub separator: String,
+
     /// Subject part of the commit message.
     pub subject: Option<String>,
 }
 
+/// Footer represents a single footer (also known as trailer) of the commit message.
+///
+/// ```code
+/// <token>: <value>
+/// <token> #<value>
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Footer {
+    /// Token of the footer such as `Refs` or `BREAKING CHANGE`.
+    pub token: String,
+
+    /// Value of the footer.
+    /// Note that the continued lines of a multi-line value are joined with a newline.
+    pub value: String,
+
+    /// Separator between the token and the value, either `:` or `#`.
+    pub separator: char,
+}
+
+impl Footer {
+    /// Check whether the footer is a `Co-authored-by` trailer.
+    pub fn is_co_author(&self) -> bool {
+        self.token.eq_ignore_ascii_case("Co-authored-by")
+    }
+
+    /// Name part of the `Name <email>` value.
+    pub fn name(&self) -> Option<&str> {
+        split_address(&self.value)
+            .map(|(name, _)| name)
+            .filter(|name| !name.is_empty())
+    }
+
+    /// Email part of the `Name <email>` value.
+    pub fn email(&self) -> Option<&str> {
+        split_address(&self.value).map(|(_, email)| email)
+    }
+}
+
+/// Split the `Name <email>` value into the name and the email.
+/// The last pair of the angle brackets is used as the email
+/// so that the name may contain commas and angle brackets.
+fn split_address(value: &str) -> Option<(&str, &str)> {
+    let value = value.trim().strip_suffix('>')?;
+    let (name, email) = value.rsplit_once('<')?;
+    Some((name.trim(), email))
+}
+
+/// Format the footer as it is written in the commit message.
+impl fmt::Display for Footer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.separator {
+            '#' => write!(f, "{} #{}", self.token, self.value),
+            _ => write!(f, "{}{} {}", self.token, self.separator, self.value),
+        }
+    }
+}
+
+/// ParseError represents the reason why the commit message is not a conventional commit.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The subject is empty.
+    EmptySubject,
+
+    /// The subject does not follow the `<type>[optional scope]: <description>` format.
+    NotConventional(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::EmptySubject => write!(f, "subject is empty"),
+            ParseError::NotConventional(subject) => write!(
+                f,
+                "subject {:?} does not follow the format of \"<type>[optional scope]: <description>\"",
+                subject
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
 /// Message represents a commit message.
 impl Message {
     /// Create a new Message.
     pub fn new(raw: String) -> Self {
+        Self::with_separator(raw, DEFAULT_SEPARATOR)
+    }
+
+    /// Create a new Message whose type and description are separated by the separator
+    /// such as `/` of `feat(cli)/ add new flag` instead of `:`.
+    pub fn with_separator(raw: String, separator: &str) -> Self {
         let (subject, body, footers) = parse_commit_message(&raw);
-        let (r#type, scope, description) = parse_subject(&subject);
+        let (r#type, scope, description) = parse_subject_with_separator(&subject, separator);
         Self {
             body,
             description,
@@ -53,14 +141,180 @@ impl Message {
             raw,
             r#type,
             scope,
+            separator: separator.to_string(),
             subject: Some(subject),
         }
     }
+
+    /// Parse the raw commit message as a conventional commit.
+    ///
+    /// Unlike `new`, an error is returned if the subject is free-form text
+    /// instead of silently leaving the type and the scope empty.
+    pub fn parse(raw: &str) -> Result<Self, ParseError> {
+        let message = Self::new(raw.to_string());
+        if message.conforms() {
+            return Ok(message);
+        }
+
+        match message.subject {
+            Some(subject) if !subject.trim().is_empty() => {
+                Err(ParseError::NotConventional(subject))
+            }
+            _ => Err(ParseError::EmptySubject),
+        }
+    }
+
+    /// Check whether the subject follows the conventional commits format.
+    pub fn conforms(&self) -> bool {
+        self.r#type.is_some()
+    }
+
+    /// Check whether the message has a breaking change indicator,
+    /// either `!` after the type or scope or a `BREAKING CHANGE` footer.
+    pub fn is_breaking(&self) -> bool {
+        self.has_breaking_mark() || self.breaking_footer().is_some()
+    }
+
+    /// Check whether the subject has `!` after the type or scope such as `feat!:`.
+    pub fn has_breaking_mark(&self) -> bool {
+        self.conforms()
+            && self
+                .subject
+                .as_deref()
+                .and_then(|subject| subject.split_once(self.separator.as_str()))
+                .is_some_and(|(header, _)| header.ends_with('!'))
+    }
+
+    /// Create a new Message from the fixed raw commit message
+    /// with the same separator as this one.
+    pub fn with_raw(&self, raw: String) -> Self {
+        Self::with_separator(raw, &self.separator)
+    }
+
+    /// Get the `BREAKING CHANGE` footer of the message.
+    /// Note that `BREAKING-CHANGE` is a synonym as described in the specification.
+    pub fn breaking_footer(&self) -> Option<&Footer> {
+        self.footers
+            .iter()
+            .flatten()
+            .find(|footer| footer.token == "BREAKING CHANGE" || footer.token == "BREAKING-CHANGE")
+    }
+
+    /// Check whether the message is generated by `git revert`.
+    ///
+    /// Note that a revert written in the conventional format such as
+    /// `revert: add new flag` is not treated as a generated one.
+    pub fn is_revert(&self) -> bool {
+        self.subject
+            .as_deref()
+            .is_some_and(|subject| subject.starts_with("Revert \""))
+    }
+
+    /// Check whether the message is a merge commit such as
+    /// `Merge branch 'main' into feature` or `Merge pull request #1 from feature`.
+    pub fn is_merge(&self) -> bool {
+        self.subject
+            .as_deref()
+            .is_some_and(|subject| subject.starts_with("Merge "))
+    }
+
+    /// Get the scopes of the message split by the delimiter such as `,` for `feat(api,ui):`.
+    /// The whole scope is returned as a single scope if no delimiter is given.
+    pub fn scopes(&self, delimiter: Option<&str>) -> Vec<&str> {
+        let scope = match self.scope.as_deref() {
+            Some(scope) => scope,
+            None => return Vec::new(),
+        };
+
+        match delimiter {
+            Some(delimiter) if !delimiter.is_empty() => scope
+                .split(delimiter)
+                .map(|scope| scope.trim())
+                .filter(|scope| !scope.is_empty())
+                .collect(),
+            _ if scope.is_empty() => Vec::new(),
+            _ => vec![scope],
+        }
+    }
+
+    /// Get the `Co-authored-by` footers of the message.
+    pub fn co_authors(&self) -> Vec<&Footer> {
+        self.footers
+            .iter()
+            .flatten()
+            .filter(|footer| footer.is_co_author())
+            .collect()
+    }
 }
 
-/// validate the raw commit message.
-pub async fn validate(msg: &Message, config: &Config) -> Result<LintResult, Error> {
-    let violations = config.rules.validate(msg);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_separator() {
+        let message = Message::with_separator("feat(cli)/ add new flag".to_string(), "/");
+
+        assert_eq!(message.r#type, Some("feat".to_string()));
+        assert_eq!(message.scope, Some("cli".to_string()));
+        assert_eq!(message.description, Some("add new flag".to_string()));
+        assert_eq!(message.subject, Some("feat(cli)/ add new flag".to_string()));
+    }
+
+    #[test]
+    fn test_scopes() {
+        let message = Message::new("feat(api, ui): x".to_string());
+
+        assert_eq!(message.scopes(Some(",")), vec!["api", "ui"]);
+        assert_eq!(message.scopes(None), vec!["api, ui"]);
+        assert!(Message::new("feat: x".to_string())
+            .scopes(Some(","))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_parse_conventional_commit() {
+        let message = Message::parse("feat(x): y").unwrap();
 
-    Ok(LintResult { violations })
+        assert!(message.conforms());
+        assert_eq!(message.r#type, Some("feat".to_string()));
+        assert_eq!(message.scope, Some("x".to_string()));
+        assert_eq!(message.description, Some("y".to_string()));
+    }
+
+    #[test]
+    fn test_parse_free_form_text() {
+        assert!(!Message::new("random text".to_string()).conforms());
+        assert_eq!(
+            Message::parse("random text").unwrap_err(),
+            ParseError::NotConventional("random text".to_string())
+        );
+        assert_eq!(
+            Message::parse("random text").unwrap_err().to_string(),
+            "subject \"random text\" does not follow the format of \"<type>[optional scope]: <description>\""
+        );
+    }
+
+    #[test]
+    fn test_is_breaking() {
+        assert!(Message::new("feat!: drop option".to_string()).is_breaking());
+        assert!(Message::new("feat(cli)!: drop option".to_string()).is_breaking());
+        assert!(Message::new(
+            "feat: drop option\n\nBREAKING CHANGE: the option is removed".to_string()
+        )
+        .is_breaking());
+        assert!(!Message::new("feat: drop option".to_string()).is_breaking());
+        assert!(!Message::new("Hello world!: drop option".to_string()).is_breaking());
+    }
+
+    #[test]
+    fn test_is_breaking_with_separator() {
+        assert!(Message::with_separator("feat(cli)!/ drop option".to_string(), "/").is_breaking());
+        assert!(!Message::with_separator("feat(cli)/ drop option!".to_string(), "/").is_breaking());
+    }
+
+    #[test]
+    fn test_parse_empty_subject() {
+        assert_eq!(Message::parse("").unwrap_err(), ParseError::EmptySubject);
+    }
 }
@@ -0,0 +1,864 @@
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::{result::Violation, rule::Level};
+
+/// Report represents the machine-readable report of the lint results.
+#[derive(Debug, Serialize)]
+struct Report<'a> {
+    /// Valid represents whether there is no error-level violation.
+    valid: bool,
+
+    /// Number of the error-level violations.
+    errors: usize,
+
+    /// Number of the warning-level violations.
+    warnings: usize,
+
+    /// Violations to be reported.
+    /// Note that ignore-level violations are not included.
+    violations: Vec<&'a Violation>,
+}
+
+/// CommitReport represents the report of a commit identified by the SHA.
+#[derive(Debug, Serialize)]
+struct CommitReport<'a> {
+    /// SHA of the commit.
+    sha: &'a str,
+
+    #[serde(flatten)]
+    report: Report<'a>,
+}
+
+/// BatchReport represents the report of the violations across the commit messages
+/// such as the duplicate subjects, which are not bound to a single commit.
+#[derive(Debug, Serialize)]
+struct BatchReport<'a> {
+    /// Batch is always true to tell the report from the ones of the commits.
+    batch: bool,
+
+    #[serde(flatten)]
+    report: Report<'a>,
+}
+
+/// Entry represents an entry of the JSON array of the reports,
+/// either the report of a commit message or the batch report.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+enum Entry<'a, T> {
+    Commit(T),
+    Batch(BatchReport<'a>),
+}
+
+/// ANSI escape sequences of the colored text output.
+const RED: &str = "\x1b[31m";
+const YELLOW: &str = "\x1b[33m";
+const DIM: &str = "\x1b[2m";
+const RESET: &str = "\x1b[0m";
+
+/// Format the violation as a human-readable line.
+///
+/// With `color`, the message is colored by the level (red for errors and yellow
+/// for warnings) and followed by the dimmed rule name.
+/// Otherwise, only the plain message is returned for the piped output.
+pub fn text(violation: &Violation, color: bool) -> String {
+    if !color {
+        return violation.message.clone();
+    }
+
+    let level = match violation.level {
+        Level::Error => RED,
+        Level::Warning => YELLOW,
+        _ => "",
+    };
+
+    format!(
+        "{}{}{} {}[{}]{}",
+        level, violation.message, RESET, DIM, violation.rule, RESET
+    )
+}
+
+/// Format the violations of the level under the header such as `errors:`.
+/// Each violation is paired with the prefix such as `commit 2: ` put before it.
+/// Returns `None` if there is no violation of the level.
+pub fn group(level: Level, violations: &[(String, &Violation)], color: bool) -> Option<String> {
+    let lines: Vec<String> = violations
+        .iter()
+        .filter(|(_, violation)| violation.level == level)
+        .map(|(prefix, violation)| format!("  {}{}", prefix, text(violation, color)))
+        .collect();
+    if lines.is_empty() {
+        return None;
+    }
+
+    let header = match level {
+        Level::Error => "errors:",
+        _ => "warnings:",
+    };
+
+    Some(format!("{}\n{}", header, lines.join("\n")))
+}
+
+/// Format the summary line counting the error-level and warning-level violations
+/// such as `✖ 2 problems (1 error, 1 warning)`.
+/// Returns `None` if there is no problem.
+pub fn summary(violations: &[Violation]) -> Option<String> {
+    let report = report(violations);
+    let problems = report.errors + report.warnings;
+    if problems == 0 {
+        return None;
+    }
+
+    Some(format!(
+        "✖ {} ({}, {})",
+        plural(problems, "problem"),
+        plural(report.errors, "error"),
+        plural(report.warnings, "warning")
+    ))
+}
+
+/// Format the count with the noun in the singular or plural form.
+fn plural(count: usize, noun: &str) -> String {
+    if count == 1 {
+        format!("{} {}", count, noun)
+    } else {
+        format!("{} {}s", count, noun)
+    }
+}
+
+/// Format the violations as JSON.
+pub fn json(violations: &[Violation]) -> String {
+    serde_json::to_string(&report(violations)).unwrap()
+}
+
+/// Format the violations of each commit message as a JSON array of reports.
+/// The reports are in the same order as the commit messages.
+/// The violations across the commit messages are appended as a batch report if any.
+pub fn json_batch(violations: &[Vec<Violation>], batch: &[Violation]) -> String {
+    let reports: Vec<Report> = violations
+        .iter()
+        .map(|violations| report(violations))
+        .collect();
+
+    json_with_batch(reports, batch)
+}
+
+/// Format the violations of each commit as a JSON array of reports with the SHAs.
+/// The SHAs and the violations are in the same order.
+/// The violations across the commits are appended as a batch report if any.
+pub fn json_commits(shas: &[String], violations: &[Vec<Violation>], batch: &[Violation]) -> String {
+    let reports: Vec<CommitReport> = shas
+        .iter()
+        .zip(violations)
+        .map(|(sha, violations)| CommitReport {
+            sha,
+            report: report(violations),
+        })
+        .collect();
+
+    json_with_batch(reports, batch)
+}
+
+/// Format the reports as a JSON array followed by the batch report
+/// if there is any violation across the commit messages.
+fn json_with_batch<T: Serialize>(reports: Vec<T>, batch: &[Violation]) -> String {
+    let mut entries: Vec<Entry<T>> = reports.into_iter().map(Entry::Commit).collect();
+
+    let report = report(batch);
+    if !report.violations.is_empty() {
+        entries.push(Entry::Batch(BatchReport {
+            batch: true,
+            report,
+        }));
+    }
+
+    serde_json::to_string(&entries).unwrap()
+}
+
+/// Source represents where the commit messages of the SARIF results are read from.
+#[derive(Clone, Copy, Debug)]
+pub enum Source<'a> {
+    /// The commit message file given by --edit.
+    File(&'a Path),
+
+    /// The commits identified by the SHAs such as given by --range.
+    Commits(&'a [String]),
+
+    /// The commit messages without the SHAs such as read from stdin,
+    /// identified by the index as in the text output.
+    Messages,
+}
+
+impl Source<'_> {
+    /// Build the location of the commit message at the index.
+    fn location(&self, index: usize) -> serde_json::Value {
+        match self {
+            Source::File(path) => serde_json::json!({
+                "physicalLocation": {
+                    "artifactLocation": {
+                        "uri": path.to_string_lossy(),
+                    },
+                    "region": {
+                        "startLine": 1,
+                    },
+                },
+            }),
+            Source::Commits(shas) => serde_json::json!({
+                "logicalLocations": [
+                    {
+                        "name": shas[index],
+                    },
+                ],
+            }),
+            Source::Messages => serde_json::json!({
+                "logicalLocations": [
+                    {
+                        "name": format!("commit {}", index + 1),
+                    },
+                ],
+            }),
+        }
+    }
+}
+
+/// Format the violations as SARIF 2.1.0 for the code scanning services.
+/// See: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+///
+/// Each result is located at the commit message it belongs to in the source.
+/// The violations across the commit messages have no location.
+///
+/// Note that ignore-level violations are not included.
+pub fn sarif(source: Source, reports: &[Vec<Violation>], batch: &[Violation]) -> String {
+    let violations: Vec<(Option<usize>, &Violation)> = reports
+        .iter()
+        .enumerate()
+        .flat_map(|(index, violations)| violations.iter().map(move |v| (Some(index), v)))
+        .chain(batch.iter().map(|violation| (None, violation)))
+        .filter(|(_, violation)| matches!(violation.level, Level::Error | Level::Warning))
+        .collect();
+
+    let mut rules: Vec<&str> = violations.iter().map(|(_, v)| v.rule.as_str()).collect();
+    rules.sort();
+    rules.dedup();
+
+    let results: Vec<serde_json::Value> = violations
+        .iter()
+        .map(|(index, violation)| {
+            let mut result = serde_json::json!({
+                "ruleId": violation.rule,
+                "level": match violation.level {
+                    Level::Error => "error",
+                    _ => "warning",
+                },
+                "message": {
+                    "text": violation.message,
+                },
+            });
+            if let Some(index) = index {
+                result["locations"] = serde_json::json!([source.location(*index)]);
+            }
+            result
+        })
+        .collect();
+
+    let log = serde_json::json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [
+            {
+                "tool": {
+                    "driver": {
+                        "name": "commitlint",
+                        "informationUri": env!("CARGO_PKG_REPOSITORY"),
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "rules": rules
+                            .iter()
+                            .map(|rule| serde_json::json!({ "id": rule }))
+                            .collect::<Vec<_>>(),
+                    },
+                },
+                "results": results,
+            },
+        ],
+    });
+
+    serde_json::to_string(&log).unwrap()
+}
+
+/// Format the lint results as JUnit XML for the CI test reporters.
+///
+/// Each commit message is a test suite and each rule is a test case,
+/// so that the rules without error-level violations are reported as passed.
+/// Warning-level violations are written to the output of the test case.
+///
+/// The batch rules are reported in the `batch` test suite after the commits
+/// if any of them ran or reported the violations across the commit messages.
+pub fn junit(
+    rules: &[String],
+    violations: &[Vec<Violation>],
+    batch_rules: &[String],
+    batch: &[Violation],
+) -> String {
+    let mut suites = String::new();
+    let mut total_tests = 0;
+    let mut total_failures = 0;
+
+    let mut push = |name: String, rules: &[String], violations: &[Violation]| {
+        let (suite, tests, failures) = testsuite(&name, rules, violations);
+        suites.push_str(&suite);
+        total_tests += tests;
+        total_failures += failures;
+    };
+
+    for (index, violations) in violations.iter().enumerate() {
+        push(format!("commit {}", index + 1), rules, violations);
+    }
+    if !batch_rules.is_empty() || !batch.is_empty() {
+        push("batch".to_string(), batch_rules, batch);
+    }
+
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites name=\"commitlint\" tests=\"{}\" failures=\"{}\">\n{}</testsuites>",
+        total_tests, total_failures, suites
+    )
+}
+
+/// Format the test suite of the rules with the violations.
+/// Returns the suite with the numbers of the test cases and the failed ones.
+fn testsuite(name: &str, rules: &[String], violations: &[Violation]) -> (String, usize, usize) {
+    // Violations can be reported by a rule which is not configured such as `ignores`.
+    let mut names: Vec<&str> = rules
+        .iter()
+        .map(|rule| rule.as_str())
+        .chain(violations.iter().map(|v| v.rule.as_str()))
+        .collect();
+    names.sort();
+    names.dedup();
+
+    let mut cases = String::new();
+    let mut failures = 0;
+    for name in &names {
+        let errors: Vec<&Violation> = violations
+            .iter()
+            .filter(|v| v.rule == *name && v.level == Level::Error)
+            .collect();
+        let warnings: Vec<&Violation> = violations
+            .iter()
+            .filter(|v| v.rule == *name && v.level == Level::Warning)
+            .collect();
+
+        if errors.is_empty() && warnings.is_empty() {
+            cases.push_str(&format!(
+                "    <testcase name=\"{}\" classname=\"commitlint\"/>\n",
+                escape_xml(name)
+            ));
+            continue;
+        }
+
+        cases.push_str(&format!(
+            "    <testcase name=\"{}\" classname=\"commitlint\">\n",
+            escape_xml(name)
+        ));
+        for error in &errors {
+            cases.push_str(&format!(
+                "      <failure type=\"{}\" message=\"{}\">{}</failure>\n",
+                escape_xml(name),
+                escape_xml(&error.message),
+                escape_xml(&error.message)
+            ));
+        }
+        if !warnings.is_empty() {
+            let output: Vec<String> = warnings
+                .iter()
+                .map(|w| format!("warning: {}", escape_xml(&w.message)))
+                .collect();
+            cases.push_str(&format!(
+                "      <system-out>{}</system-out>\n",
+                output.join("\n")
+            ));
+        }
+        cases.push_str("    </testcase>\n");
+
+        if !errors.is_empty() {
+            failures += 1;
+        }
+    }
+
+    let suite = format!(
+        "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\">\n{}  </testsuite>\n",
+        escape_xml(name),
+        names.len(),
+        failures,
+        cases
+    );
+
+    (suite, names.len(), failures)
+}
+
+/// Escape the special characters of XML.
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Build the report of the violations.
+fn report(violations: &[Violation]) -> Report<'_> {
+    let violations: Vec<&Violation> = violations
+        .iter()
+        .filter(|violation| violation.level != Level::Ignore)
+        .collect();
+
+    let errors = violations
+        .iter()
+        .filter(|violation| violation.level == Level::Error)
+        .count();
+    let warnings = violations
+        .iter()
+        .filter(|violation| violation.level == Level::Warning)
+        .count();
+
+    Report {
+        valid: errors == 0,
+        errors,
+        warnings,
+        violations,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text() {
+        let violation = Violation {
+            level: Level::Error,
+            message: "type is empty".to_string(),
+            rule: "type-empty".to_string(),
+        };
+
+        assert_eq!(text(&violation, false), "type is empty");
+        assert_eq!(
+            text(&violation, true),
+            "\x1b[31mtype is empty\x1b[0m \x1b[2m[type-empty]\x1b[0m"
+        );
+
+        let violation = Violation {
+            level: Level::Warning,
+            ..violation
+        };
+        assert_eq!(
+            text(&violation, true),
+            "\x1b[33mtype is empty\x1b[0m \x1b[2m[type-empty]\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_group() {
+        let warning = Violation {
+            level: Level::Warning,
+            message: "body is empty".to_string(),
+            rule: "body-empty".to_string(),
+        };
+        let error = Violation {
+            level: Level::Error,
+            message: "type is empty".to_string(),
+            rule: "type-empty".to_string(),
+        };
+        let violations = vec![
+            ("commit 1: ".to_string(), &warning),
+            ("commit 1: ".to_string(), &error),
+            ("commit 2: ".to_string(), &warning),
+            ("commit 3: ".to_string(), &error),
+        ];
+
+        assert_eq!(
+            group(Level::Error, &violations, false),
+            Some("errors:\n  commit 1: type is empty\n  commit 3: type is empty".to_string())
+        );
+        assert_eq!(
+            group(Level::Warning, &violations, false),
+            Some("warnings:\n  commit 1: body is empty\n  commit 2: body is empty".to_string())
+        );
+        assert_eq!(group(Level::Error, &violations[..1], false), None);
+    }
+
+    #[test]
+    fn test_summary() {
+        let violations = vec![
+            Violation {
+                level: Level::Error,
+                message: "type is empty".to_string(),
+                rule: "type-empty".to_string(),
+            },
+            Violation {
+                level: Level::Warning,
+                message: "body is empty".to_string(),
+                rule: "body-empty".to_string(),
+            },
+            Violation {
+                level: Level::Ignore,
+                message: "scope is empty".to_string(),
+                rule: "scope-empty".to_string(),
+            },
+        ];
+
+        assert_eq!(
+            summary(&violations),
+            Some("✖ 2 problems (1 error, 1 warning)".to_string())
+        );
+        assert_eq!(
+            summary(&violations[1..]),
+            Some("✖ 1 problem (0 errors, 1 warning)".to_string())
+        );
+        assert_eq!(summary(&violations[2..]), None);
+    }
+
+    #[test]
+    fn test_json() {
+        let violations = vec![
+            Violation {
+                level: Level::Error,
+                message: "type is empty".to_string(),
+                rule: "type-empty".to_string(),
+            },
+            Violation {
+                level: Level::Warning,
+                message: "body is empty".to_string(),
+                rule: "body-empty".to_string(),
+            },
+            Violation {
+                level: Level::Ignore,
+                message: "scope is empty".to_string(),
+                rule: "scope-empty".to_string(),
+            },
+        ];
+
+        let report: serde_json::Value = serde_json::from_str(&json(&violations)).unwrap();
+        assert_eq!(
+            report,
+            serde_json::json!({
+                "valid": false,
+                "errors": 1,
+                "warnings": 1,
+                "violations": [
+                    {
+                        "level": "error",
+                        "message": "type is empty",
+                        "rule": "type-empty",
+                    },
+                    {
+                        "level": "warning",
+                        "message": "body is empty",
+                        "rule": "body-empty",
+                    },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn test_json_without_violations() {
+        let report: serde_json::Value = serde_json::from_str(&json(&[])).unwrap();
+        assert_eq!(
+            report,
+            serde_json::json!({
+                "valid": true,
+                "errors": 0,
+                "warnings": 0,
+                "violations": [],
+            })
+        );
+    }
+
+    #[test]
+    fn test_json_commits() {
+        let shas = vec!["1a2b3c".to_string(), "4d5e6f".to_string()];
+        let violations = vec![
+            vec![],
+            vec![Violation {
+                level: Level::Warning,
+                message: "body is empty".to_string(),
+                rule: "body-empty".to_string(),
+            }],
+        ];
+
+        let reports: serde_json::Value =
+            serde_json::from_str(&json_commits(&shas, &violations, &[])).unwrap();
+        assert_eq!(
+            reports,
+            serde_json::json!([
+                {
+                    "sha": "1a2b3c",
+                    "valid": true,
+                    "errors": 0,
+                    "warnings": 0,
+                    "violations": [],
+                },
+                {
+                    "sha": "4d5e6f",
+                    "valid": true,
+                    "errors": 0,
+                    "warnings": 1,
+                    "violations": [
+                        {
+                            "level": "warning",
+                            "message": "body is empty",
+                            "rule": "body-empty",
+                        }
+                    ],
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_json_batch() {
+        let violations = vec![
+            vec![],
+            vec![Violation {
+                level: Level::Error,
+                message: "type is empty".to_string(),
+                rule: "type-empty".to_string(),
+            }],
+        ];
+
+        let reports: serde_json::Value =
+            serde_json::from_str(&json_batch(&violations, &[])).unwrap();
+        assert_eq!(
+            reports,
+            serde_json::json!([
+                {
+                    "valid": true,
+                    "errors": 0,
+                    "warnings": 0,
+                    "violations": [],
+                },
+                {
+                    "valid": false,
+                    "errors": 1,
+                    "warnings": 0,
+                    "violations": [
+                        {
+                            "level": "error",
+                            "message": "type is empty",
+                            "rule": "type-empty",
+                        },
+                    ],
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_json_batch_with_batch_violations() {
+        let batch = vec![Violation {
+            level: Level::Error,
+            message: "subject \"feat: a\" is duplicated".to_string(),
+            rule: "no-duplicate-subject".to_string(),
+        }];
+
+        let reports: serde_json::Value =
+            serde_json::from_str(&json_batch(&[vec![], vec![]], &batch)).unwrap();
+        assert_eq!(reports.as_array().unwrap().len(), 3);
+        assert_eq!(
+            reports[2],
+            serde_json::json!({
+                "batch": true,
+                "valid": false,
+                "errors": 1,
+                "warnings": 0,
+                "violations": [
+                    {
+                        "level": "error",
+                        "message": "subject \"feat: a\" is duplicated",
+                        "rule": "no-duplicate-subject",
+                    },
+                ],
+            })
+        );
+
+        let shas = vec!["1a2b3c".to_string(), "4d5e6f".to_string()];
+        let reports: serde_json::Value =
+            serde_json::from_str(&json_commits(&shas, &[vec![], vec![]], &batch)).unwrap();
+        assert_eq!(reports[2]["batch"], true);
+        assert!(reports[2].get("sha").is_none());
+    }
+
+    #[test]
+    fn test_sarif() {
+        let violations = vec![
+            Violation {
+                level: Level::Error,
+                message: "type is empty".to_string(),
+                rule: "type-empty".to_string(),
+            },
+            Violation {
+                level: Level::Warning,
+                message: "body is empty".to_string(),
+                rule: "body-empty".to_string(),
+            },
+            Violation {
+                level: Level::Ignore,
+                message: "scope is empty".to_string(),
+                rule: "scope-empty".to_string(),
+            },
+        ];
+
+        let log: serde_json::Value = serde_json::from_str(&sarif(
+            Source::File(Path::new(".git/COMMIT_EDITMSG")),
+            &[violations],
+            &[],
+        ))
+        .unwrap();
+        assert_eq!(log["version"], "2.1.0");
+        assert_eq!(
+            log["runs"][0]["tool"]["driver"]["rules"],
+            serde_json::json!([{ "id": "body-empty" }, { "id": "type-empty" }])
+        );
+
+        let results = log["runs"][0]["results"].as_array().unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0]["ruleId"], "type-empty");
+        assert_eq!(results[0]["level"], "error");
+        assert_eq!(results[0]["message"]["text"], "type is empty");
+        assert_eq!(
+            results[0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            ".git/COMMIT_EDITMSG"
+        );
+        assert_eq!(
+            results[0]["locations"][0]["physicalLocation"]["region"]["startLine"],
+            1
+        );
+        assert_eq!(results[1]["ruleId"], "body-empty");
+        assert_eq!(results[1]["level"], "warning");
+    }
+
+    #[test]
+    fn test_sarif_locations() {
+        let violation = |rule: &str| Violation {
+            level: Level::Error,
+            message: format!("{} is violated", rule),
+            rule: rule.to_string(),
+        };
+        let reports = vec![
+            vec![violation("type-empty")],
+            vec![violation("scope-empty")],
+        ];
+        let batch = vec![violation("no-duplicate-subject")];
+
+        let shas = vec!["1a2b3c".to_string(), "4d5e6f".to_string()];
+        let log: serde_json::Value =
+            serde_json::from_str(&sarif(Source::Commits(&shas), &reports, &batch)).unwrap();
+        let results = log["runs"][0]["results"].as_array().unwrap();
+        assert_eq!(results.len(), 3);
+        assert_eq!(
+            results[0]["locations"][0]["logicalLocations"][0]["name"],
+            "1a2b3c"
+        );
+        assert_eq!(
+            results[1]["locations"][0]["logicalLocations"][0]["name"],
+            "4d5e6f"
+        );
+        assert!(results[2].get("locations").is_none());
+
+        let log: serde_json::Value =
+            serde_json::from_str(&sarif(Source::Messages, &reports, &batch)).unwrap();
+        let results = log["runs"][0]["results"].as_array().unwrap();
+        assert_eq!(
+            results[1]["locations"][0]["logicalLocations"][0]["name"],
+            "commit 2"
+        );
+        assert!(results[0]["locations"][0].get("physicalLocation").is_none());
+    }
+
+    #[test]
+    fn test_junit() {
+        let rules = vec![
+            "body-empty".to_string(),
+            "scope-empty".to_string(),
+            "type-empty".to_string(),
+        ];
+        let violations = vec![vec![
+            Violation {
+                level: Level::Error,
+                message: "type is empty. Prefix with \"feat: \"".to_string(),
+                rule: "type-empty".to_string(),
+            },
+            Violation {
+                level: Level::Warning,
+                message: "body is empty".to_string(),
+                rule: "body-empty".to_string(),
+            },
+        ]];
+
+        assert_eq!(
+            junit(&rules, &violations, &[], &[]),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="commitlint" tests="3" failures="1">
+  <testsuite name="commit 1" tests="3" failures="1">
+    <testcase name="body-empty" classname="commitlint">
+      <system-out>warning: body is empty</system-out>
+    </testcase>
+    <testcase name="scope-empty" classname="commitlint"/>
+    <testcase name="type-empty" classname="commitlint">
+      <failure type="type-empty" message="type is empty. Prefix with &quot;feat: &quot;">type is empty. Prefix with &quot;feat: &quot;</failure>
+    </testcase>
+  </testsuite>
+</testsuites>"#
+        );
+    }
+
+    #[test]
+    fn test_junit_without_violations() {
+        let rules = vec!["scope-empty".to_string(), "type-empty".to_string()];
+
+        assert_eq!(
+            junit(&rules, &[vec![]], &[], &[]),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="commitlint" tests="2" failures="0">
+  <testsuite name="commit 1" tests="2" failures="0">
+    <testcase name="scope-empty" classname="commitlint"/>
+    <testcase name="type-empty" classname="commitlint"/>
+  </testsuite>
+</testsuites>"#
+        );
+    }
+
+    #[test]
+    fn test_junit_with_batch() {
+        let rules = vec!["type-empty".to_string()];
+        let batch_rules = vec![
+            "no-duplicate-subject".to_string(),
+            "scope-case-consistency".to_string(),
+        ];
+        let batch = vec![Violation {
+            level: Level::Error,
+            message: "subject \"feat: a\" is duplicated".to_string(),
+            rule: "no-duplicate-subject".to_string(),
+        }];
+
+        assert_eq!(
+            junit(&rules, &[vec![], vec![]], &batch_rules, &batch),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="commitlint" tests="4" failures="1">
+  <testsuite name="commit 1" tests="1" failures="0">
+    <testcase name="type-empty" classname="commitlint"/>
+  </testsuite>
+  <testsuite name="commit 2" tests="1" failures="0">
+    <testcase name="type-empty" classname="commitlint"/>
+  </testsuite>
+  <testsuite name="batch" tests="2" failures="1">
+    <testcase name="no-duplicate-subject" classname="commitlint">
+      <failure type="no-duplicate-subject" message="subject &quot;feat: a&quot; is duplicated">subject &quot;feat: a&quot; is duplicated</failure>
+    </testcase>
+    <testcase name="scope-case-consistency" classname="commitlint"/>
+  </testsuite>
+</testsuites>"#
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use regex::Regex;
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+/// Cache represents the compiled regular expressions keyed by the pattern.
+///
+/// Linting many commits validates the same patterns over and over,
+/// so that each distinct pattern is compiled only once per thread.
+/// The cache is not shared across the threads, so that the threads linting
+/// the commit messages in parallel never wait for each other.
+#[derive(Debug, Default)]
+struct Cache {
+    /// Compiled regular expressions.
+    regexes: RefCell<HashMap<String, Rc<Regex>>>,
+
+    /// Number of the compilations to check the cache in the tests.
+    #[cfg(test)]
+    compilations: std::cell::Cell<usize>,
+}
+
+impl Cache {
+    /// Get the compiled regular expression of the pattern.
+    ///
+    /// The invalid pattern is not cached, so that the error is returned every time
+    /// and the rules using it can report a violation for each message.
+    fn get(&self, pattern: &str) -> Result<Rc<Regex>, regex::Error> {
+        if let Some(regex) = self.regexes.borrow().get(pattern) {
+            return Ok(Rc::clone(regex));
+        }
+
+        let regex = Rc::new(Regex::new(pattern)?);
+        #[cfg(test)]
+        self.compilations.set(self.compilations.get() + 1);
+        self.regexes
+            .borrow_mut()
+            .insert(pattern.to_string(), Rc::clone(&regex));
+
+        Ok(regex)
+    }
+}
+
+/// Compile the pattern once per thread and return the cached regular expression.
+pub fn compile(pattern: &str) -> Result<Rc<Regex>, regex::Error> {
+    thread_local! {
+        static CACHE: Cache = Cache::default();
+    }
+    CACHE.with(|cache| cache.get(pattern))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compile_once() {
+        let cache = Cache::default();
+
+        for _ in 0..100 {
+            assert!(cache.get(r"^feat").unwrap().is_match("feat: add new flag"));
+        }
+        assert!(cache.get(r"^fix").unwrap().is_match("fix: typo"));
+
+        assert_eq!(cache.compilations.get(), 2);
+    }
+
+    #[test]
+    fn test_invalid_pattern() {
+        let cache = Cache::default();
+
+        assert!(cache.get(r"(").is_err());
+        assert!(cache.get(r"(").is_err());
+
+        assert_eq!(cache.compilations.get(), 0);
+    }
+
+    #[test]
+    fn test_compile() {
+        assert!(compile(r"^\w+$").unwrap().is_match("feat"));
+        assert!(compile(r"[").is_err());
+    }
+}
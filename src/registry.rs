@@ -0,0 +1,200 @@
+//! Registry of the rules to construct them by name.
+//!
+//! The registry maps the rule names to the constructors, so that only the
+//! rules named in the configuration are instantiated.
+use std::collections::HashMap;
+
+use serde::de::{DeserializeOwned, Error};
+
+use crate::rule::{deserialize_rule, DynRule, Rule, Rules, Visitor};
+
+/// Constructor creates a rule from the options of the configuration.
+/// It returns `None` if the rule is disabled with the `off` level.
+pub type Constructor = fn(serde_json::Value) -> Result<Option<Box<dyn DynRule>>, serde_json::Error>;
+
+/// Construct the rule of the type from the options.
+fn construct<T>(options: serde_json::Value) -> Result<Option<Box<dyn DynRule>>, serde_json::Error>
+where
+    T: Rule + DeserializeOwned + 'static,
+{
+    deserialize_rule::<_, T>(options)
+        .map(|rule| rule.map(|rule| Box::new(rule) as Box<dyn DynRule>))
+        .map_err(|err| Error::custom(format!("{}: {}", T::NAME, err)))
+}
+
+/// Registry maps the rule names to the constructors.
+#[derive(Default)]
+pub struct Registry {
+    constructors: HashMap<&'static str, Constructor>,
+}
+
+impl Registry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a registry with all the built-in rules.
+    /// The rules are the same as the ones listed by `Rules::rules`.
+    pub fn builtin() -> Self {
+        let mut registry = Self::new();
+        Rules::default().visit(&mut registry);
+        registry
+    }
+
+    /// Register the rule with its name.
+    /// The rule registered with the same name is replaced.
+    pub fn register<T>(&mut self)
+    where
+        T: Rule + DeserializeOwned + 'static,
+    {
+        self.constructors.insert(T::NAME, construct::<T>);
+    }
+
+    /// Get the names of the registered rules in ascending order.
+    pub fn names(&self) -> Vec<&'static str> {
+        let mut names: Vec<&'static str> = self.constructors.keys().copied().collect();
+        names.sort();
+        names
+    }
+
+    /// Construct the rule by the name from the options.
+    pub fn construct(
+        &self,
+        name: &str,
+        options: serde_json::Value,
+    ) -> Result<Option<Box<dyn DynRule>>, serde_json::Error> {
+        match self.constructors.get(name) {
+            Some(constructor) => constructor(options),
+            None => Err(Error::custom(format!("unknown rule: {}", name))),
+        }
+    }
+
+    /// Construct the rules named in the `rules` section of the configuration.
+    /// The disabled rules are skipped and the rules are sorted by the name.
+    pub fn build(
+        &self,
+        rules: &serde_json::Value,
+    ) -> Result<Vec<Box<dyn DynRule>>, serde_json::Error> {
+        let rules = match rules {
+            serde_json::Value::Object(rules) => rules,
+            serde_json::Value::Null => return Ok(Vec::new()),
+            _ => return Err(Error::custom("rules must be a mapping")),
+        };
+
+        let mut built = Vec::new();
+        for (name, options) in rules {
+            if let Some(rule) = self.construct(name, options.clone())? {
+                built.push(rule);
+            }
+        }
+
+        built.sort_by_key(|rule| rule.name());
+        Ok(built)
+    }
+}
+
+/// Register the rules visited in `Rules::visit`.
+impl<'a> Visitor<'a> for Registry {
+    fn visit<T>(&mut self, _: &'a Option<T>)
+    where
+        T: Rule + DeserializeOwned + 'static,
+    {
+        self.register::<T>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{message::Message, rule::Level};
+
+    #[test]
+    fn test_builtin_names() {
+        let names: Vec<&str> = Rules::default()
+            .rules()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+
+        assert_eq!(Registry::builtin().names(), names);
+    }
+
+    #[test]
+    fn test_construct_from_options() {
+        let registry = Registry::builtin();
+        let options = serde_json::json!({"level": "warning", "pattern": r"#[A-Z]+-\d+"});
+
+        let rule = registry
+            .construct("ticket-consistency", options)
+            .unwrap()
+            .unwrap();
+        assert_eq!(rule.name(), "ticket-consistency");
+
+        let violations = rule.validate_all(&Message::new(
+            "feat(cli): #ABC-1 add new flag\n\nThis is for #ABC-2.".to_string(),
+        ));
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].level, Level::Warning);
+        assert_eq!(
+            violations[0].message,
+            "ticket IDs of the body {\"#ABC-2\"} must be the same as the subject {\"#ABC-1\"}"
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_construct_invalid_options() {
+        let registry = Registry::builtin();
+        let options = serde_json::json!({"level": "warning", "pattern": 1});
+
+        let err = registry
+            .construct("ticket-consistency", options)
+            .err()
+            .unwrap();
+        assert!(
+            err.to_string().starts_with("ticket-consistency: "),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_construct_disabled_rule() {
+        let registry = Registry::builtin();
+        let options = serde_json::json!({"level": "off"});
+
+        assert!(registry.construct("body-empty", options).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_construct_unknown_rule() {
+        let registry = Registry::builtin();
+
+        assert_eq!(
+            registry
+                .construct("unknown", serde_json::Value::Null)
+                .err()
+                .map(|err| err.to_string()),
+            Some("unknown rule: unknown".to_string())
+        );
+    }
+
+    #[test]
+    fn test_build() {
+        let registry = Registry::builtin();
+        let rules = serde_json::json!({
+            "type-empty": {"level": "error"},
+            "body-empty": {"level": "warning"},
+            "scope-empty": {"level": "off"}
+        });
+
+        let names: Vec<&str> = registry
+            .build(&rules)
+            .unwrap()
+            .iter()
+            .map(|rule| rule.name())
+            .collect();
+        assert_eq!(names, vec!["body-empty", "type-empty"]);
+    }
+}
@@ -1,19 +1,136 @@
+use serde::Serialize;
+
 use crate::rule::Level;
 
-/// Result of the check.
-#[derive(Clone, Debug)]
-pub struct Result {
-    /// List of violations to be printed.
-    /// If it is empty, then there is no violation.
-    pub violations: Vec<Violation>,
+/// LintResult is the result of the check with the violations classified by the level.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct LintResult {
+    /// Valid represents whether there is no error-level violation.
+    /// Note that the warnings do not make the result invalid.
+    pub valid: bool,
+
+    /// List of error-level violations.
+    pub errors: Vec<Violation>,
+
+    /// List of warning-level violations.
+    pub warnings: Vec<Violation>,
+}
+
+/// Classify the violations by the level.
+/// Ignore-level violations are dropped as they are neither errors nor warnings.
+impl From<Vec<Violation>> for LintResult {
+    fn from(violations: Vec<Violation>) -> Self {
+        let (errors, warnings): (Vec<Violation>, Vec<Violation>) = violations
+            .into_iter()
+            .filter(|violation| matches!(violation.level, Level::Error | Level::Warning))
+            .partition(|violation| violation.level == Level::Error);
+
+        Self {
+            valid: errors.is_empty(),
+            errors,
+            warnings,
+        }
+    }
 }
 
 /// Violation is a message that will be printed.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
 pub struct Violation {
     /// Level of the violation.
     pub level: Level,
 
     /// Message of the violation.
     pub message: String,
+
+    /// Name of the rule which reported the violation.
+    pub rule: String,
+}
+
+/// Check whether any of the violations is error-level.
+///
+/// Warning-level violations are reported but do not fail the lint,
+/// so that the exit status only depends on the error-level ones.
+pub fn has_error(violations: &[Violation]) -> bool {
+    violations
+        .iter()
+        .any(|violation| violation.level == Level::Error)
+}
+
+/// Check whether the violations fail the lint.
+///
+/// In the strict mode, the warning-level violations also fail the lint
+/// while they are still reported as warnings.
+pub fn has_failure(violations: &[Violation], strict: bool) -> bool {
+    violations.iter().any(|violation| match violation.level {
+        Level::Error => true,
+        Level::Warning => strict,
+        _ => false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn violation(level: Level, rule: &str) -> Violation {
+        Violation {
+            level,
+            message: format!("{} is violated", rule),
+            rule: rule.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_lint_result_from_violations() {
+        let result = LintResult::from(vec![
+            violation(Level::Warning, "body-empty"),
+            violation(Level::Error, "scope-empty"),
+            violation(Level::Ignore, "subject-case"),
+            violation(Level::Error, "type-enum"),
+        ]);
+
+        assert!(!result.valid);
+        assert_eq!(
+            result.errors,
+            vec![
+                violation(Level::Error, "scope-empty"),
+                violation(Level::Error, "type-enum"),
+            ]
+        );
+        assert_eq!(
+            result.warnings,
+            vec![violation(Level::Warning, "body-empty")]
+        );
+    }
+
+    #[test]
+    fn test_lint_result_with_only_warnings() {
+        let result = LintResult::from(vec![violation(Level::Warning, "body-empty")]);
+
+        assert!(result.valid);
+        assert!(result.errors.is_empty());
+        assert_eq!(result.warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_lint_result_without_violations() {
+        let result = LintResult::from(Vec::new());
+
+        assert!(result.valid);
+        assert!(result.errors.is_empty());
+        assert!(result.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_has_failure() {
+        let warnings = vec![violation(Level::Warning, "body-empty")];
+        assert!(!has_failure(&warnings, false));
+        assert!(has_failure(&warnings, true));
+
+        let errors = vec![violation(Level::Error, "scope-empty")];
+        assert!(has_failure(&errors, false));
+
+        let ignored = vec![violation(Level::Ignore, "subject-case")];
+        assert!(!has_failure(&ignored, true));
+    }
 }
@@ -1,182 +1,620 @@
 use std::fmt::Debug;
 
 use crate::{message::Message, result::Violation};
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
 
 use self::{
-    body_empty::BodyEmpty, body_max_length::BodyMaxLength, description_empty::DescriptionEmpty,
+    body_empty::BodyEmpty, body_leading_blank::BodyLeadingBlank, body_max_length::BodyMaxLength,
+    body_max_line_length::BodyMaxLineLength, body_max_lines::BodyMaxLines,
+    body_min_length::BodyMinLength, breaking_change::BreakingChange,
+    breaking_change_token::BreakingChangeToken, breaking_requires_body::BreakingRequiresBody,
+    co_author_email::CoAuthorEmail, description_empty::DescriptionEmpty,
     description_format::DescriptionFormat, description_max_length::DescriptionMaxLength,
-    r#type::Type, scope::Scope, scope_empty::ScopeEmpty, scope_format::ScopeFormat,
-    scope_max_length::ScopeMaxLength, subject_empty::SubjectEmpty, type_empty::TypeEmpty,
-    type_format::TypeFormat, type_max_length::TypeMaxLength,
+    footer_case::FooterCase, footer_exists::FooterExists, footer_leading_blank::FooterLeadingBlank,
+    footer_max_line_length::FooterMaxLineLength, footer_references_format::FooterReferencesFormat,
+    footer_value_not_empty::FooterValueNotEmpty, gitmoji_type::GitmojiType,
+    header_max_length::HeaderMaxLength, header_min_length::HeaderMinLength,
+    header_pattern::HeaderPattern, header_separator::HeaderSeparator,
+    no_consecutive_blank_lines::NoConsecutiveBlankLines, no_duplicate_subject::NoDuplicateSubject,
+    no_emoji_subject::NoEmojiSubject, no_fixup::NoFixup, no_ticket_in_subject::NoTicketInSubject,
+    no_trailing_blank_lines::NoTrailingBlankLines, no_trailing_whitespace::NoTrailingWhitespace,
+    no_type_in_subject::NoTypeInSubject, r#type::Type, references_empty::ReferencesEmpty,
+    revert_hash::RevertHash, revert_subject_match::RevertSubjectMatch, scope::Scope,
+    scope_case::ScopeCase, scope_case_consistency::ScopeCaseConsistency, scope_empty::ScopeEmpty,
+    scope_enum::ScopeEnum, scope_format::ScopeFormat, scope_max_length::ScopeMaxLength,
+    scope_not_type::ScopeNotType, scope_required_for_types::ScopeRequiredForTypes,
+    signed_off_by::SignedOffBy, subject_allowed_chars::SubjectAllowedChars,
+    subject_case::SubjectCase, subject_empty::SubjectEmpty, subject_full_stop::SubjectFullStop,
+    subject_imperative::SubjectImperative, subject_max_length::SubjectMaxLength,
+    subject_max_words::SubjectMaxWords, subject_min_length::SubjectMinLength,
+    subject_mood_wordlist::SubjectMoodWordlist, ticket_consistency::TicketConsistency,
+    type_case::TypeCase, type_empty::TypeEmpty, type_enum::TypeEnum, type_format::TypeFormat,
+    type_max_length::TypeMaxLength, word_blocklist::WordBlocklist,
 };
 
 pub mod body_empty;
+pub mod body_leading_blank;
 pub mod body_max_length;
+pub mod body_max_line_length;
+pub mod body_max_lines;
+pub mod body_min_length;
+pub mod breaking_change;
+pub mod breaking_change_token;
+pub mod breaking_requires_body;
+pub mod co_author_email;
 pub mod description_empty;
 pub mod description_format;
 pub mod description_max_length;
+pub mod footer_case;
+pub mod footer_exists;
+pub mod footer_leading_blank;
+pub mod footer_max_line_length;
+pub mod footer_references_format;
+pub mod footer_value_not_empty;
+pub mod gitmoji_type;
+pub mod header_max_length;
+pub mod header_min_length;
+pub mod header_pattern;
+pub mod header_separator;
+pub mod no_consecutive_blank_lines;
+pub mod no_duplicate_subject;
+pub mod no_emoji_subject;
+pub mod no_fixup;
+pub mod no_ticket_in_subject;
+pub mod no_trailing_blank_lines;
+pub mod no_trailing_whitespace;
+pub mod no_type_in_subject;
+pub mod references_empty;
+pub mod revert_hash;
+pub mod revert_subject_match;
 pub mod scope;
+pub mod scope_case;
+pub mod scope_case_consistency;
 pub mod scope_empty;
+pub mod scope_enum;
 pub mod scope_format;
 pub mod scope_max_length;
+pub mod scope_not_type;
+pub mod scope_required_for_types;
+pub mod signed_off_by;
+pub mod subject_allowed_chars;
+pub mod subject_case;
 pub mod subject_empty;
+pub mod subject_full_stop;
+pub mod subject_imperative;
+pub mod subject_max_length;
+pub mod subject_max_words;
+pub mod subject_min_length;
+pub mod subject_mood_wordlist;
+pub mod ticket_consistency;
 pub mod r#type;
+pub mod type_case;
 pub mod type_empty;
+pub mod type_enum;
 pub mod type_format;
 pub mod type_max_length;
+pub mod word_blocklist;
 
 /// Rules represents the rules of commitlint.
 /// See: https://commitlint.js.org/#/reference-rules
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Rules {
     #[serde(rename = "body-empty")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub body_empty: Option<BodyEmpty>,
 
+    #[serde(rename = "body-leading-blank")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_leading_blank: Option<BodyLeadingBlank>,
+
     #[serde(rename = "body-max-length")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub body_max_length: Option<BodyMaxLength>,
 
+    #[serde(rename = "body-max-line-length")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_max_line_length: Option<BodyMaxLineLength>,
+
+    #[serde(rename = "body-max-lines")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_max_lines: Option<BodyMaxLines>,
+
+    #[serde(rename = "body-min-length")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_min_length: Option<BodyMinLength>,
+
+    #[serde(rename = "breaking-change")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub breaking_change: Option<BreakingChange>,
+
+    #[serde(rename = "breaking-change-token")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub breaking_change_token: Option<BreakingChangeToken>,
+
+    #[serde(rename = "breaking-requires-body")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub breaking_requires_body: Option<BreakingRequiresBody>,
+
+    #[serde(rename = "co-author-email")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub co_author_email: Option<CoAuthorEmail>,
+
     #[serde(rename = "description-empty")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description_empty: Option<DescriptionEmpty>,
 
     #[serde(rename = "description-format")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description_format: Option<DescriptionFormat>,
 
     #[serde(rename = "description-max-length")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description_max_length: Option<DescriptionMaxLength>,
 
+    #[serde(rename = "footer-case")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer_case: Option<FooterCase>,
+
+    #[serde(rename = "footer-exists")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer_exists: Option<FooterExists>,
+
+    #[serde(rename = "footer-leading-blank")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer_leading_blank: Option<FooterLeadingBlank>,
+
+    #[serde(rename = "footer-max-line-length")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer_max_line_length: Option<FooterMaxLineLength>,
+
+    #[serde(rename = "footer-references-format")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer_references_format: Option<FooterReferencesFormat>,
+
+    #[serde(rename = "footer-value-not-empty")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer_value_not_empty: Option<FooterValueNotEmpty>,
+
+    #[serde(rename = "gitmoji-type")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gitmoji_type: Option<GitmojiType>,
+
+    #[serde(rename = "header-max-length")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header_max_length: Option<HeaderMaxLength>,
+
+    #[serde(rename = "header-min-length")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header_min_length: Option<HeaderMinLength>,
+
+    #[serde(rename = "header-pattern")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header_pattern: Option<HeaderPattern>,
+
+    #[serde(rename = "header-separator")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header_separator: Option<HeaderSeparator>,
+
+    #[serde(rename = "no-consecutive-blank-lines")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_consecutive_blank_lines: Option<NoConsecutiveBlankLines>,
+
+    #[serde(rename = "no-duplicate-subject")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_duplicate_subject: Option<NoDuplicateSubject>,
+
+    #[serde(rename = "no-emoji-subject")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_emoji_subject: Option<NoEmojiSubject>,
+
+    #[serde(rename = "no-fixup")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_fixup: Option<NoFixup>,
+
+    #[serde(rename = "no-ticket-in-subject")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_ticket_in_subject: Option<NoTicketInSubject>,
+
+    #[serde(rename = "no-trailing-blank-lines")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_trailing_blank_lines: Option<NoTrailingBlankLines>,
+
+    #[serde(rename = "no-trailing-whitespace")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_trailing_whitespace: Option<NoTrailingWhitespace>,
+
+    #[serde(rename = "no-type-in-subject")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_type_in_subject: Option<NoTypeInSubject>,
+
+    #[serde(rename = "references-empty")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub references_empty: Option<ReferencesEmpty>,
+
+    #[serde(rename = "revert-hash")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revert_hash: Option<RevertHash>,
+
+    #[serde(rename = "revert-subject-match")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revert_subject_match: Option<RevertSubjectMatch>,
+
     #[serde(rename = "scope")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scope: Option<Scope>,
 
+    #[serde(rename = "scope-case")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope_case: Option<ScopeCase>,
+
+    #[serde(rename = "scope-case-consistency")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope_case_consistency: Option<ScopeCaseConsistency>,
+
     #[serde(rename = "scope-empty")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scope_empty: Option<ScopeEmpty>,
 
+    #[serde(rename = "scope-enum")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope_enum: Option<ScopeEnum>,
+
     #[serde(rename = "scope-format")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scope_format: Option<ScopeFormat>,
 
     #[serde(rename = "scope-max-length")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scope_max_length: Option<ScopeMaxLength>,
 
+    #[serde(rename = "scope-not-type")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope_not_type: Option<ScopeNotType>,
+
+    #[serde(rename = "scope-required-for-types")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope_required_for_types: Option<ScopeRequiredForTypes>,
+
+    #[serde(rename = "signed-off-by")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signed_off_by: Option<SignedOffBy>,
+
+    #[serde(rename = "subject-allowed-chars")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject_allowed_chars: Option<SubjectAllowedChars>,
+
+    #[serde(rename = "subject-case")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject_case: Option<SubjectCase>,
+
     #[serde(rename = "subject-empty")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subject_empty: Option<SubjectEmpty>,
 
+    #[serde(rename = "subject-full-stop")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject_full_stop: Option<SubjectFullStop>,
+
+    #[serde(rename = "subject-imperative")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject_imperative: Option<SubjectImperative>,
+
+    #[serde(rename = "subject-max-length")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject_max_length: Option<SubjectMaxLength>,
+
+    #[serde(rename = "subject-max-words")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject_max_words: Option<SubjectMaxWords>,
+
+    #[serde(rename = "subject-min-length")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject_min_length: Option<SubjectMinLength>,
+
+    #[serde(rename = "subject-mood-wordlist")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject_mood_wordlist: Option<SubjectMoodWordlist>,
+
+    #[serde(rename = "ticket-consistency")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ticket_consistency: Option<TicketConsistency>,
+
     #[serde(rename = "type")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub r#type: Option<Type>,
 
+    #[serde(rename = "type-case")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_case: Option<TypeCase>,
+
     #[serde(rename = "type-empty")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub type_empty: Option<TypeEmpty>,
 
+    #[serde(rename = "type-enum")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_enum: Option<TypeEnum>,
+
     #[serde(rename = "type-format")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub type_format: Option<TypeFormat>,
 
     #[serde(rename = "type-max-length")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub type_max_length: Option<TypeMaxLength>,
+
+    #[serde(rename = "word-blocklist")]
+    #[serde(default, deserialize_with = "deserialize_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub word_blocklist: Option<WordBlocklist>,
 }
 
 /// Rule is a collection of rules.
 impl Rules {
-    pub fn validate(&self, message: &Message) -> Vec<Violation> {
-        let mut results = Vec::new();
-
-        if let Some(rule) = &self.body_empty {
-            if let Some(validation) = rule.validate(message) {
-                results.push(validation);
-            }
-        }
-
-        if let Some(rule) = &self.body_max_length {
-            if let Some(validation) = rule.validate(message) {
-                results.push(validation);
-            }
-        }
-
-        if let Some(rule) = &self.description_empty {
-            if let Some(validation) = rule.validate(message) {
-                results.push(validation);
-            }
-        }
-
-        if let Some(rule) = &self.description_format {
-            if let Some(validation) = rule.validate(message) {
-                results.push(validation);
-            }
-        }
-
-        if let Some(rule) = &self.description_max_length {
-            if let Some(validation) = rule.validate(message) {
-                results.push(validation);
+    /// Get all the rules for a single commit message with the names in ascending order.
+    /// The rules which are not configured are included as `None`.
+    pub fn rules(&self) -> Vec<(&'static str, Option<&dyn DynRule>)> {
+        struct Entries<'a>(Vec<(&'static str, Option<&'a dyn DynRule>)>);
+
+        impl<'a> Visitor<'a> for Entries<'a> {
+            fn visit<T>(&mut self, rule: &'a Option<T>)
+            where
+                T: Rule + DeserializeOwned + 'static,
+            {
+                self.0
+                    .push((T::NAME, rule.as_ref().map(|rule| rule as &dyn DynRule)));
             }
         }
 
-        if let Some(rule) = &self.scope {
-            if let Some(validation) = rule.validate(message) {
-                results.push(validation);
-            }
-        }
+        let mut entries = Entries(Vec::new());
+        self.visit(&mut entries);
+        entries.0
+    }
 
-        if let Some(rule) = &self.scope_empty {
-            if let Some(validation) = rule.validate(message) {
-                results.push(validation);
-            }
-        }
+    /// Visit all the rules for a single commit message with their types in ascending order.
+    pub(crate) fn visit<'a, V: Visitor<'a>>(&'a self, visitor: &mut V) {
+        // The fields are destructured without `..` so that a new rule cannot be left out.
+        // Note that the batch rules are listed by `batch_rules`.
+        let Rules {
+            body_empty,
+            body_leading_blank,
+            body_max_length,
+            body_max_line_length,
+            body_max_lines,
+            body_min_length,
+            breaking_change,
+            breaking_change_token,
+            breaking_requires_body,
+            co_author_email,
+            description_empty,
+            description_format,
+            description_max_length,
+            footer_case,
+            footer_exists,
+            footer_leading_blank,
+            footer_max_line_length,
+            footer_references_format,
+            footer_value_not_empty,
+            gitmoji_type,
+            header_max_length,
+            header_min_length,
+            header_pattern,
+            header_separator,
+            no_consecutive_blank_lines,
+            no_duplicate_subject: _,
+            no_emoji_subject,
+            no_fixup,
+            no_ticket_in_subject,
+            no_trailing_blank_lines,
+            no_trailing_whitespace,
+            no_type_in_subject,
+            references_empty,
+            revert_hash,
+            revert_subject_match,
+            scope,
+            scope_case,
+            scope_case_consistency: _,
+            scope_empty,
+            scope_enum,
+            scope_format,
+            scope_max_length,
+            scope_not_type,
+            scope_required_for_types,
+            signed_off_by,
+            subject_allowed_chars,
+            subject_case,
+            subject_empty,
+            subject_full_stop,
+            subject_imperative,
+            subject_max_length,
+            subject_max_words,
+            subject_min_length,
+            subject_mood_wordlist,
+            ticket_consistency,
+            r#type,
+            type_case,
+            type_empty,
+            type_enum,
+            type_format,
+            type_max_length,
+            word_blocklist,
+        } = self;
+
+        visitor.visit(body_empty);
+        visitor.visit(body_leading_blank);
+        visitor.visit(body_max_length);
+        visitor.visit(body_max_line_length);
+        visitor.visit(body_max_lines);
+        visitor.visit(body_min_length);
+        visitor.visit(breaking_change);
+        visitor.visit(breaking_change_token);
+        visitor.visit(breaking_requires_body);
+        visitor.visit(co_author_email);
+        visitor.visit(description_empty);
+        visitor.visit(description_format);
+        visitor.visit(description_max_length);
+        visitor.visit(footer_case);
+        visitor.visit(footer_exists);
+        visitor.visit(footer_leading_blank);
+        visitor.visit(footer_max_line_length);
+        visitor.visit(footer_references_format);
+        visitor.visit(footer_value_not_empty);
+        visitor.visit(gitmoji_type);
+        visitor.visit(header_max_length);
+        visitor.visit(header_min_length);
+        visitor.visit(header_pattern);
+        visitor.visit(header_separator);
+        visitor.visit(no_consecutive_blank_lines);
+        visitor.visit(no_emoji_subject);
+        visitor.visit(no_fixup);
+        visitor.visit(no_ticket_in_subject);
+        visitor.visit(no_trailing_blank_lines);
+        visitor.visit(no_trailing_whitespace);
+        visitor.visit(no_type_in_subject);
+        visitor.visit(references_empty);
+        visitor.visit(revert_hash);
+        visitor.visit(revert_subject_match);
+        visitor.visit(scope);
+        visitor.visit(scope_case);
+        visitor.visit(scope_empty);
+        visitor.visit(scope_enum);
+        visitor.visit(scope_format);
+        visitor.visit(scope_max_length);
+        visitor.visit(scope_not_type);
+        visitor.visit(scope_required_for_types);
+        visitor.visit(signed_off_by);
+        visitor.visit(subject_allowed_chars);
+        visitor.visit(subject_case);
+        visitor.visit(subject_empty);
+        visitor.visit(subject_full_stop);
+        visitor.visit(subject_imperative);
+        visitor.visit(subject_max_length);
+        visitor.visit(subject_max_words);
+        visitor.visit(subject_min_length);
+        visitor.visit(subject_mood_wordlist);
+        visitor.visit(ticket_consistency);
+        visitor.visit(r#type);
+        visitor.visit(type_case);
+        visitor.visit(type_empty);
+        visitor.visit(type_enum);
+        visitor.visit(type_format);
+        visitor.visit(type_max_length);
+        visitor.visit(word_blocklist);
+    }
 
-        if let Some(rule) = &self.scope_format {
-            if let Some(validation) = rule.validate(message) {
-                results.push(validation);
-            }
+    /// Get all the batch rules with the names in ascending order.
+    /// The rules which are not configured are included as `None`.
+    pub fn batch_rules(&self) -> Vec<(&'static str, Option<&dyn DynBatchRule>)> {
+        fn entry<T: BatchRule>(rule: &Option<T>) -> (&'static str, Option<&dyn DynBatchRule>) {
+            (T::NAME, rule.as_ref().map(|rule| rule as &dyn DynBatchRule))
         }
 
-        if let Some(rule) = &self.scope_max_length {
-            if let Some(validation) = rule.validate(message) {
-                results.push(validation);
-            }
-        }
+        vec![
+            entry(&self.no_duplicate_subject),
+            entry(&self.scope_case_consistency),
+        ]
+    }
 
-        if let Some(rule) = &self.subject_empty {
-            if let Some(validation) = rule.validate(message) {
-                results.push(validation);
-            }
-        }
+    pub fn validate(&self, message: &Message) -> Vec<Violation> {
+        self.validate_with(message, |rule, message| rule.validate_all(message))
+    }
 
-        if let Some(rule) = &self.r#type {
-            if let Some(validation) = rule.validate(message) {
-                results.push(validation);
-            }
-        }
+    /// Validate the commit message with `run` running each configured rule,
+    /// so that the rules can be observed such as timed by the trace.
+    pub fn validate_with<F>(&self, message: &Message, mut run: F) -> Vec<Violation>
+    where
+        F: FnMut(&dyn DynRule, &Message) -> Vec<Violation>,
+    {
+        self.rules()
+            .into_iter()
+            .filter_map(|(_, rule)| rule)
+            .flat_map(|rule| run(rule, message))
+            .collect()
+    }
 
-        if let Some(rule) = &self.type_empty {
-            if let Some(validation) = rule.validate(message) {
-                results.push(validation);
-            }
-        }
+    /// Validate the commit messages together with the batch rules.
+    pub fn validate_batch(&self, messages: &[Message]) -> Vec<Violation> {
+        self.validate_batch_with(messages, |rule, messages| rule.validate_batch(messages))
+    }
 
-        if let Some(rule) = &self.type_format {
-            if let Some(validation) = rule.validate(message) {
-                results.push(validation);
-            }
-        }
+    /// Validate the commit messages together with `run` running each configured batch rule.
+    pub fn validate_batch_with<F>(&self, messages: &[Message], mut run: F) -> Vec<Violation>
+    where
+        F: FnMut(&dyn DynBatchRule, &[Message]) -> Vec<Violation>,
+    {
+        self.batch_rules()
+            .into_iter()
+            .filter_map(|(_, rule)| rule)
+            .flat_map(|rule| run(rule, messages))
+            .collect()
+    }
 
-        if let Some(rule) = &self.type_max_length {
-            if let Some(validation) = rule.validate(message) {
-                results.push(validation);
-            }
+    /// Fix the message with the rules which can fix it automatically.
+    pub fn fix(&self, message: &mut Message) {
+        for rule in self.rules().into_iter().filter_map(|(_, rule)| rule) {
+            rule.fix(message);
         }
-
-        results
     }
 }
 
@@ -186,23 +624,79 @@ impl Default for Rules {
     fn default() -> Self {
         Self {
             body_empty: None,
+            body_leading_blank: None,
             body_max_length: None,
+            body_max_line_length: None,
+            body_max_lines: None,
+            body_min_length: None,
+            breaking_change: None,
+            breaking_change_token: None,
+            breaking_requires_body: None,
+            co_author_email: None,
             description_empty: DescriptionEmpty::default().into(),
             description_format: None,
             description_max_length: None,
+            footer_case: None,
+            footer_exists: None,
+            footer_leading_blank: None,
+            footer_max_line_length: None,
+            footer_references_format: None,
+            footer_value_not_empty: None,
+            gitmoji_type: None,
+            header_max_length: None,
+            header_min_length: None,
+            header_pattern: None,
+            header_separator: None,
+            no_consecutive_blank_lines: None,
+            no_duplicate_subject: None,
+            no_emoji_subject: None,
+            no_fixup: None,
+            no_ticket_in_subject: None,
+            no_trailing_blank_lines: None,
+            no_trailing_whitespace: None,
+            no_type_in_subject: None,
+            references_empty: None,
+            revert_hash: None,
+            revert_subject_match: None,
             scope: None,
+            scope_case: None,
+            scope_case_consistency: None,
             scope_empty: None,
+            scope_enum: None,
             scope_format: None,
             scope_max_length: None,
+            scope_not_type: None,
+            scope_required_for_types: None,
+            signed_off_by: None,
+            subject_allowed_chars: None,
+            subject_case: None,
             subject_empty: SubjectEmpty::default().into(),
+            subject_full_stop: None,
+            subject_imperative: None,
+            subject_max_length: None,
+            subject_max_words: None,
+            subject_min_length: None,
+            subject_mood_wordlist: None,
+            ticket_consistency: None,
             r#type: None,
+            type_case: None,
             type_empty: TypeEmpty::default().into(),
+            type_enum: None,
             type_format: None,
             type_max_length: None,
+            word_blocklist: None,
         }
     }
 }
 
+/// Render the custom message of a rule.
+/// The `{found}` and `{expected}` placeholders are replaced with the given values.
+pub(crate) fn render_message(template: &str, found: &str, expected: &str) -> String {
+    template
+        .replace("{found}", found)
+        .replace("{expected}", expected)
+}
+
 /// Rule trait represents a rule that can be applied to a text.
 pub trait Rule: Default {
     /// The name of the rule.
@@ -217,6 +711,114 @@ pub trait Rule: Default {
 
     /// Validate the given text.
     fn validate(&self, message: &Message) -> Option<Violation>;
+
+    /// Validate the given text and return all the violations.
+    ///
+    /// Override it if the rule can detect multiple problems in a single pass.
+    /// By default, it returns the violation of `validate` if any.
+    fn validate_all(&self, message: &Message) -> Vec<Violation> {
+        self.validate(message).into_iter().collect()
+    }
+
+    /// Fix the given message in place.
+    /// Note that only the rules which can fix the message automatically implement it.
+    fn fix(&self, _message: &mut Message) {}
+}
+
+/// BatchRule trait represents a rule that is applied to multiple commit messages together,
+/// such as the consistency of the scopes across the commits of a batch.
+pub trait BatchRule: Default {
+    /// The name of the rule.
+    /// Note that it should be unique among the rules and the batch rules.
+    const NAME: &'static str;
+
+    /// The level of the rule.
+    const LEVEL: Level;
+
+    /// Validate the commit messages together and return all the violations.
+    fn validate_batch(&self, messages: &[Message]) -> Vec<Violation>;
+}
+
+/// Visitor visits the rules of `Rules` with their types,
+/// so that the list of the rules is written only in `Rules::visit`.
+pub(crate) trait Visitor<'a> {
+    /// Visit the rule which is `None` if it is not configured.
+    fn visit<T>(&mut self, rule: &'a Option<T>)
+    where
+        T: Rule + DeserializeOwned + 'static;
+}
+
+/// DynRule is the object-safe counterpart of `Rule`.
+///
+/// It is implemented for all the rules, so that the rules of different types
+/// can be iterated together.
+pub trait DynRule {
+    /// The name of the rule.
+    fn name(&self) -> &'static str;
+
+    /// Validate the given text and return all the violations.
+    fn validate_all(&self, message: &Message) -> Vec<Violation>;
+
+    /// Fix the given message in place.
+    fn fix(&self, message: &mut Message);
+}
+
+impl<T: Rule> DynRule for T {
+    fn name(&self) -> &'static str {
+        T::NAME
+    }
+
+    fn validate_all(&self, message: &Message) -> Vec<Violation> {
+        Rule::validate_all(self, message)
+    }
+
+    fn fix(&self, message: &mut Message) {
+        Rule::fix(self, message)
+    }
+}
+
+/// DynBatchRule is the object-safe counterpart of `BatchRule`.
+pub trait DynBatchRule {
+    /// The name of the rule.
+    fn name(&self) -> &'static str;
+
+    /// Validate the commit messages together and return all the violations.
+    fn validate_batch(&self, messages: &[Message]) -> Vec<Violation>;
+}
+
+impl<T: BatchRule> DynBatchRule for T {
+    fn name(&self) -> &'static str {
+        T::NAME
+    }
+
+    fn validate_batch(&self, messages: &[Message]) -> Vec<Violation> {
+        BatchRule::validate_batch(self, messages)
+    }
+}
+
+/// Deserialize the configuration of a rule.
+/// The rule with the `off` level is disabled and treated as if it were not configured,
+/// so that it is neither validated nor fixed.
+pub(crate) fn deserialize_rule<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    let value = serde_json::Value::deserialize(deserializer)?;
+    if value.is_null() {
+        return Ok(None);
+    }
+
+    let level = value
+        .get("level")
+        .and_then(|level| Level::deserialize(level).ok());
+    if level == Some(Level::Off) {
+        return Ok(None);
+    }
+
+    T::deserialize(value)
+        .map(Some)
+        .map_err(serde::de::Error::custom)
 }
 
 /// Level represents the level of a rule.
@@ -228,6 +830,87 @@ pub enum Level {
     #[serde(rename = "ignore")]
     Ignore,
 
+    /// Off disables the rule.
+    #[serde(rename = "off")]
+    Off,
+
     #[serde(rename = "warning")]
     Warning,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_configured_rules() {
+        let names: Vec<&str> = Rules::default()
+            .rules()
+            .into_iter()
+            .filter_map(|(name, rule)| rule.map(|_| name))
+            .collect();
+
+        assert_eq!(
+            names,
+            vec!["description-empty", "subject-empty", "type-empty"]
+        );
+    }
+
+    #[test]
+    fn test_deserialize_disabled_rule() {
+        let rules: Rules = serde_json::from_str(
+            r#"{"body-empty": {"level": "off"}, "scope-empty": {"level": "warning"}, "type-empty": null}"#,
+        )
+        .unwrap();
+
+        assert!(rules.body_empty.is_none());
+        assert!(rules.scope_empty.is_some());
+        assert!(rules.type_empty.is_none());
+    }
+
+    #[test]
+    fn test_render_message() {
+        assert_eq!(
+            render_message("use {expected} instead of {found}", "chore", "[\"feat\"]"),
+            "use [\"feat\"] instead of chore".to_string()
+        );
+        assert_eq!(
+            render_message("no placeholders", "chore", "[]"),
+            "no placeholders"
+        );
+    }
+
+    #[test]
+    fn test_validate_all_without_violation() {
+        let rule = TypeEmpty::default();
+        let message = Message::new("feat(cli): add new flag".to_string());
+
+        assert!(Rule::validate_all(&rule, &message).is_empty());
+    }
+
+    #[test]
+    fn test_validate_all_with_violation() {
+        let rule = TypeEmpty::default();
+        let message = Message::new("add new flag".to_string());
+
+        let violations = Rule::validate_all(&rule, &message);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].rule, "type-empty".to_string());
+    }
+
+    #[test]
+    fn test_rules_collect_all_violations() {
+        let rules = Rules::default();
+        let message = Message::new("".to_string());
+
+        let names: Vec<String> = rules
+            .validate(&message)
+            .into_iter()
+            .map(|violation| violation.rule)
+            .collect();
+        assert_eq!(
+            names,
+            vec!["description-empty", "subject-empty", "type-empty"]
+        );
+    }
+}
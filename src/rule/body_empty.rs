@@ -11,6 +11,11 @@ pub struct BodyEmpty {
     // Note that currently the default literal is not supported.
     // See: https://github.com/serde-rs/serde/issues/368
     level: Option<Level>,
+
+    /// Forbidden represents whether the body must be empty.
+    /// If it is false, the body must not be empty instead.
+    #[serde(default)]
+    forbidden: bool,
 }
 
 /// BodyEmpty represents the body-empty rule.
@@ -19,14 +24,25 @@ impl Rule for BodyEmpty {
     const LEVEL: Level = Level::Error;
 
     fn message(&self, _message: &Message) -> String {
+        if self.forbidden {
+            return "body is not empty".to_string();
+        }
+
         "body is empty".to_string()
     }
 
     fn validate(&self, message: &Message) -> Option<Violation> {
-        if message.body.is_none() {
+        // Note that the body only containing whitespace is empty.
+        let is_empty = match &message.body {
+            None => true,
+            Some(body) => body.trim().is_empty(),
+        };
+
+        if is_empty != self.forbidden {
             return Some(Violation {
                 level: self.level.unwrap_or(Self::LEVEL),
                 message: self.message(message),
+                rule: Self::NAME.to_string(),
             });
         }
 
@@ -39,6 +55,7 @@ impl Default for BodyEmpty {
     fn default() -> Self {
         Self {
             level: Some(Self::LEVEL),
+            forbidden: false,
         }
     }
 }
@@ -60,6 +77,7 @@ mod tests {
 Hello world"
                 .to_string(),
             scope: Some("scope".to_string()),
+            separator: ":".to_string(),
             subject: Some("feat(scope): broadcast $destroy event on scope destruction".to_string()),
         };
 
@@ -76,6 +94,7 @@ Hello world"
             r#type: Some("feat".to_string()),
             raw: "feat(scope): broadcast $destroy event on scope destruction".to_string(),
             scope: Some("scope".to_string()),
+            separator: ":".to_string(),
             subject: None,
         };
 
@@ -84,4 +103,72 @@ Hello world"
         assert_eq!(violation.clone().unwrap().level, Level::Error);
         assert_eq!(violation.unwrap().message, "body is empty".to_string());
     }
+
+    #[test]
+    fn test_blank_body() {
+        let rule = BodyEmpty::default();
+        let message = Message {
+            body: Some("  \t".to_string()),
+            description: Some("broadcast $destroy event on scope destruction".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(scope): broadcast $destroy event on scope destruction
+
+  \t"
+            .to_string(),
+            scope: Some("scope".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(scope): broadcast $destroy event on scope destruction".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.unwrap().message, "body is empty".to_string());
+    }
+
+    #[test]
+    fn test_forbidden_empty_body() {
+        let rule = BodyEmpty {
+            forbidden: true,
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: Some("bump regex".to_string()),
+            footers: None,
+            r#type: Some("chore".to_string()),
+            raw: "chore(deps): bump regex".to_string(),
+            scope: Some("deps".to_string()),
+            separator: ":".to_string(),
+            subject: Some("chore(deps): bump regex".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_forbidden_non_empty_body() {
+        let rule = BodyEmpty {
+            forbidden: true,
+            ..Default::default()
+        };
+        let message = Message {
+            body: Some("Hello world".to_string()),
+            description: Some("bump regex".to_string()),
+            footers: None,
+            r#type: Some("chore".to_string()),
+            raw: "chore(deps): bump regex
+
+Hello world"
+                .to_string(),
+            scope: Some("deps".to_string()),
+            separator: ":".to_string(),
+            subject: Some("chore(deps): bump regex".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(violation.unwrap().message, "body is not empty".to_string());
+    }
 }
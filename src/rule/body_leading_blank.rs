@@ -0,0 +1,117 @@
+use crate::{message::Message, result::Violation, rule::Rule};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// BodyLeadingBlank represents the body-leading-blank rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BodyLeadingBlank {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+}
+
+/// BodyLeadingBlank represents the body-leading-blank rule.
+impl Rule for BodyLeadingBlank {
+    const NAME: &'static str = "body-leading-blank";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, _message: &Message) -> String {
+        "body must have a leading blank line".to_string()
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        let mut lines = message
+            .raw
+            .lines()
+            .skip_while(|line| line.trim().is_empty())
+            .skip(1); // Skip the header.
+
+        match lines.next() {
+            Some(line) if !line.trim().is_empty() => Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Default implementation of BodyLeadingBlank.
+impl Default for BodyLeadingBlank {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_leading_blank() {
+        let rule = BodyLeadingBlank::default();
+        let message = Message {
+            body: Some("Hello world".to_string()),
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag
+
+Hello world"
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_no_leading_blank() {
+        let rule = BodyLeadingBlank::default();
+        let message = Message {
+            body: Some("Hello world".to_string()),
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag
+Hello world"
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "body must have a leading blank line".to_string()
+        );
+    }
+
+    #[test]
+    fn test_header_only() {
+        let rule = BodyLeadingBlank::default();
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag".to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{message::Message, result::Violation, rule::Rule};
+use crate::{message::Message, result::Violation, rule::Rule, width::display_width};
 use serde::{Deserialize, Serialize};
 
 use super::Level;
@@ -28,10 +28,11 @@ impl Rule for BodyMaxLength {
     fn validate(&self, message: &Message) -> Option<Violation> {
         match &message.body {
             Some(body) => {
-                if body.len() >= self.length {
+                if display_width(body) >= self.length {
                     return Some(Violation {
                         level: self.level.unwrap_or(Self::LEVEL),
                         message: self.message(message),
+                        rule: Self::NAME.to_string(),
                     });
                 }
             }
@@ -39,6 +40,7 @@ impl Rule for BodyMaxLength {
                 return Some(Violation {
                     level: self.level.unwrap_or(Self::LEVEL),
                     message: self.message(message),
+                    rule: Self::NAME.to_string(),
                 })
             }
         }
@@ -77,6 +79,7 @@ mod tests {
 Hey!"
                 .to_string(),
             scope: Some("scope".to_string()),
+            separator: ":".to_string(),
             subject: Some("feat(scope): broadcast $destroy event on scope destruction".to_string()),
         };
 
@@ -99,6 +102,7 @@ Hey!"
 Hello, I'm a long body"
                 .to_string(),
             scope: Some("scope".to_string()),
+            separator: ":".to_string(),
             subject: None,
         };
 
@@ -0,0 +1,197 @@
+use crate::{message::Message, result::Violation, rule::Rule, width::display_width};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// BodyMaxLineLength represents the body-max-line-length rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BodyMaxLineLength {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Length represents the maximum length of each line of the body.
+    length: usize,
+
+    /// IgnoreUrls represents whether the lines only containing an URL are ignored.
+    #[serde(default)]
+    ignore_urls: bool,
+}
+
+impl BodyMaxLineLength {
+    /// Find the first line longer than the maximum length.
+    /// Returns the line number (starting from 1) and the length of the line.
+    fn find_long_line(&self, body: &str) -> Option<(usize, usize)> {
+        body.lines()
+            .enumerate()
+            .filter(|(_, line)| !(self.ignore_urls && is_url(line)))
+            .map(|(i, line)| (i + 1, display_width(line)))
+            .find(|(_, length)| *length > self.length)
+    }
+}
+
+/// Check whether the line only contains an URL.
+fn is_url(line: &str) -> bool {
+    let line = line.trim();
+    (line.starts_with("http://") || line.starts_with("https://"))
+        && !line.contains(char::is_whitespace)
+}
+
+/// BodyMaxLineLength represents the body-max-line-length rule.
+impl Rule for BodyMaxLineLength {
+    const NAME: &'static str = "body-max-line-length";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        match message
+            .body
+            .as_ref()
+            .and_then(|body| self.find_long_line(body))
+        {
+            Some((line, length)) => format!(
+                "body line {} is {} characters, longer than {} characters",
+                line, length, self.length
+            ),
+            None => format!("body line is longer than {} characters", self.length),
+        }
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if let Some(body) = &message.body {
+            if self.find_long_line(body).is_some() {
+                return Some(Violation {
+                    level: self.level.unwrap_or(Self::LEVEL),
+                    message: self.message(message),
+                    rule: Self::NAME.to_string(),
+                });
+            }
+        }
+
+        None
+    }
+}
+
+/// Default implementation of BodyMaxLineLength.
+impl Default for BodyMaxLineLength {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            length: 100,
+            ignore_urls: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_lines() {
+        let rule = BodyMaxLineLength {
+            length: 20,
+            ..Default::default()
+        };
+        let message = Message {
+            body: Some("Hello world\nHow are you?".to_string()),
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(scope): add new flag
+
+Hello world
+How are you?"
+                .to_string(),
+            scope: Some("scope".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(scope): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_long_third_line() {
+        let rule = BodyMaxLineLength {
+            length: 20,
+            ..Default::default()
+        };
+        let message = Message {
+            body: Some("Hello world\nHow are you?\nI'm a very long line of the body".to_string()),
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(scope): add new flag
+
+Hello world
+How are you?
+I'm a very long line of the body"
+                .to_string(),
+            scope: Some("scope".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(scope): add new flag".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "body line 3 is 32 characters, longer than 20 characters".to_string()
+        );
+    }
+
+    #[test]
+    fn test_ignore_urls() {
+        let rule = BodyMaxLineLength {
+            length: 20,
+            ignore_urls: true,
+            ..Default::default()
+        };
+        let message = Message {
+            body: Some("https://github.com/KeisukeYamashita/commitlint-rs/issues".to_string()),
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(scope): add new flag
+
+https://github.com/KeisukeYamashita/commitlint-rs/issues"
+                .to_string(),
+            scope: Some("scope".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(scope): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_urls_not_ignored() {
+        let rule = BodyMaxLineLength {
+            length: 20,
+            ..Default::default()
+        };
+        let message = Message {
+            body: Some("https://github.com/KeisukeYamashita/commitlint-rs/issues".to_string()),
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(scope): add new flag
+
+https://github.com/KeisukeYamashita/commitlint-rs/issues"
+                .to_string(),
+            scope: Some("scope".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(scope): add new flag".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(
+            violation.unwrap().message,
+            "body line 1 is 56 characters, longer than 20 characters".to_string()
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use crate::{message::Message, result::Violation, rule::Rule};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// BodyMaxLines represents the body-max-lines rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BodyMaxLines {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Count represents the maximum number of lines of the body.
+    count: usize,
+
+    /// IgnoreBlankLines represents whether the blank lines are not counted.
+    #[serde(default)]
+    ignore_blank_lines: bool,
+}
+
+impl BodyMaxLines {
+    /// Count the lines of the body.
+    /// Note that the footers are not part of the body, so that they are not counted.
+    fn count_lines(&self, message: &Message) -> usize {
+        message
+            .body
+            .as_deref()
+            .unwrap_or_default()
+            .lines()
+            .filter(|line| !(self.ignore_blank_lines && line.trim().is_empty()))
+            .count()
+    }
+}
+
+/// BodyMaxLines represents the body-max-lines rule.
+impl Rule for BodyMaxLines {
+    const NAME: &'static str = "body-max-lines";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        format!(
+            "body has more than {} lines (found {})",
+            self.count,
+            self.count_lines(message)
+        )
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if self.count_lines(message) > self.count {
+            return Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            });
+        }
+
+        None
+    }
+}
+
+/// Default implementation of BodyMaxLines.
+impl Default for BodyMaxLines {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            count: 20,
+            ignore_blank_lines: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines_at_limit() {
+        let rule = BodyMaxLines {
+            count: 2,
+            ..Default::default()
+        };
+        let message = Message {
+            body: Some("Hello world\nHow are you?".to_string()),
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag
+
+Hello world
+How are you?"
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_too_many_lines() {
+        let rule = BodyMaxLines {
+            count: 2,
+            ..Default::default()
+        };
+        let message = Message {
+            body: Some("Hello world\n\nHow are you?".to_string()),
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag
+
+Hello world
+
+How are you?"
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "body has more than 2 lines (found 3)".to_string()
+        );
+    }
+
+    #[test]
+    fn test_ignore_blank_lines() {
+        let rule = BodyMaxLines {
+            count: 2,
+            ignore_blank_lines: true,
+            ..Default::default()
+        };
+        let message = Message {
+            body: Some("Hello world\n\nHow are you?".to_string()),
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag
+
+Hello world
+
+How are you?"
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+}
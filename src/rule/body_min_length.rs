@@ -0,0 +1,127 @@
+use crate::{message::Message, result::Violation, rule::Rule, width::display_width};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// BodyMinLength represents the body-min-length rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BodyMinLength {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Length represents the minimum length of the body.
+    length: usize,
+}
+
+/// BodyMinLength represents the body-min-length rule.
+impl Rule for BodyMinLength {
+    const NAME: &'static str = "body-min-length";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        format!(
+            "body is shorter than {} characters (found {})",
+            self.length,
+            display_width(message.body.as_deref().unwrap_or_default().trim())
+        )
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        // Note that the missing body is checked by the body-empty rule.
+        let body = match &message.body {
+            Some(body) if !body.trim().is_empty() => body,
+            _ => return None,
+        };
+
+        if display_width(body.trim()) < self.length {
+            return Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            });
+        }
+
+        None
+    }
+}
+
+/// Default implementation of BodyMinLength.
+impl Default for BodyMinLength {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            length: 20,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_long_body() {
+        let rule = BodyMinLength::default();
+        let message = Message {
+            body: Some("Add new flag to print the version".to_string()),
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag
+
+Add new flag to print the version"
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_short_body() {
+        let rule = BodyMinLength::default();
+        let message = Message {
+            body: Some("See title".to_string()),
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag
+
+See title"
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "body is shorter than 20 characters (found 9)".to_string()
+        );
+    }
+
+    #[test]
+    fn test_no_body() {
+        let rule = BodyMinLength::default();
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag".to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+}
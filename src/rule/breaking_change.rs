@@ -0,0 +1,196 @@
+use crate::{message::Message, result::Violation, rule::Rule, width::display_width};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// BreakingChange represents the breaking-change rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BreakingChange {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Length represents the minimum length of the explanation in the `BREAKING CHANGE` footer.
+    #[serde(default = "default_length")]
+    length: usize,
+
+    /// Consistent represents whether the `!` is required if the `BREAKING CHANGE` footer exists.
+    #[serde(default)]
+    consistent: bool,
+}
+
+/// Default minimum length of the explanation.
+fn default_length() -> usize {
+    10
+}
+
+impl BreakingChange {
+    /// Check the breaking change and return the problem if any.
+    fn check(&self, message: &Message) -> Option<String> {
+        let footer = message.breaking_footer();
+
+        if !message.has_breaking_mark() {
+            // The message without any indicator is not a breaking change.
+            footer?;
+
+            if self.consistent {
+                return Some(
+                    "breaking change must also be indicated by \"!\" after the type or scope"
+                        .to_string(),
+                );
+            }
+        }
+
+        match footer {
+            Some(footer) if display_width(footer.value.trim()) >= self.length => None,
+            _ => Some(format!(
+                "breaking change must be explained in \"BREAKING CHANGE\" footer of at least {} characters",
+                self.length
+            )),
+        }
+    }
+}
+
+/// BreakingChange represents the breaking-change rule.
+impl Rule for BreakingChange {
+    const NAME: &'static str = "breaking-change";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        self.check(message)
+            .unwrap_or_else(|| "breaking change is invalid".to_string())
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if self.check(message).is_some() {
+            return Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            });
+        }
+
+        None
+    }
+}
+
+/// Default implementation of BreakingChange.
+impl Default for BreakingChange {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            length: default_length(),
+            consistent: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message::Footer;
+
+    #[test]
+    fn test_explained_breaking_change() {
+        let rule = BreakingChange::default();
+        let message = Message {
+            body: None,
+            description: Some("drop deprecated option".to_string()),
+            footers: Some(vec![Footer {
+                token: "BREAKING CHANGE".to_string(),
+                value: "the --legacy option is removed".to_string(),
+                separator: ':',
+            }]),
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli)!: drop deprecated option
+
+BREAKING CHANGE: the --legacy option is removed"
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli)!: drop deprecated option".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_unexplained_breaking_change() {
+        let rule = BreakingChange::default();
+        let message = Message {
+            body: None,
+            description: Some("drop deprecated option".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli)!: drop deprecated option".to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli)!: drop deprecated option".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "breaking change must be explained in \"BREAKING CHANGE\" footer of at least 10 characters"
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_short_explanation() {
+        let rule = BreakingChange::default();
+        let message = Message::new(
+            "feat(cli): drop deprecated option
+
+BREAKING CHANGE: removed"
+                .to_string(),
+        );
+
+        assert!(rule.validate(&message).is_some());
+    }
+
+    #[test]
+    fn test_not_breaking_change() {
+        let rule = BreakingChange {
+            consistent: true,
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag".to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_inconsistent_breaking_change() {
+        let rule = BreakingChange {
+            consistent: true,
+            ..Default::default()
+        };
+        let message = Message::new(
+            "feat(cli): drop deprecated option
+
+BREAKING CHANGE: the --legacy option is removed"
+                .to_string(),
+        );
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(
+            violation.unwrap().message,
+            "breaking change must also be indicated by \"!\" after the type or scope".to_string()
+        );
+    }
+}
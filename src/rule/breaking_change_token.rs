@@ -0,0 +1,140 @@
+use crate::{message::Message, pattern, result::Violation, rule::Rule};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// Tokens of the breaking change footer allowed by the specification.
+/// See: https://www.conventionalcommits.org/en/v1.0.0/#specification
+const TOKENS: [&str; 2] = ["BREAKING CHANGE", "BREAKING-CHANGE"];
+
+/// BreakingChangeToken represents the breaking-change-token rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BreakingChangeToken {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+}
+
+impl BreakingChangeToken {
+    /// Find the tokens of the breaking change footers which are not spelled exactly.
+    ///
+    /// Note that the misspelled tokens such as `Breaking Changes` are not parsed as
+    /// footers, so that the lines of the raw message are scanned instead.
+    fn find_invalid<'a>(&self, message: &'a Message) -> Vec<&'a str> {
+        let re = pattern::compile(r"(?i)^(?P<token>breaking[ _-]?changes?)\s*:").unwrap();
+
+        message
+            .raw
+            .lines()
+            .skip(1) // Skip the header.
+            .filter_map(|line| re.captures(line.trim_start()))
+            .filter_map(|captures| captures.name("token"))
+            .map(|token| token.as_str())
+            .filter(|token| !TOKENS.contains(token))
+            .collect()
+    }
+
+    /// Build the violation of the token.
+    fn violation(&self, token: &str) -> Violation {
+        Violation {
+            level: self.level.unwrap_or(Self::LEVEL),
+            message: format!(
+                "breaking change token {:?} must be one of {:?}",
+                token, TOKENS
+            ),
+            rule: Self::NAME.to_string(),
+        }
+    }
+}
+
+/// BreakingChangeToken represents the breaking-change-token rule.
+impl Rule for BreakingChangeToken {
+    const NAME: &'static str = "breaking-change-token";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        match self.find_invalid(message).first() {
+            Some(token) => self.violation(token).message,
+            None => format!("breaking change token must be one of {:?}", TOKENS),
+        }
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        self.validate_all(message).into_iter().next()
+    }
+
+    fn validate_all(&self, message: &Message) -> Vec<Violation> {
+        self.find_invalid(message)
+            .into_iter()
+            .map(|token| self.violation(token))
+            .collect()
+    }
+}
+
+/// Default implementation of BreakingChangeToken.
+impl Default for BreakingChangeToken {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message::Footer;
+
+    #[test]
+    fn test_exact_token() {
+        let rule = BreakingChangeToken::default();
+        let message = Message {
+            body: None,
+            description: Some("drop the old flag".to_string()),
+            footers: Some(vec![Footer {
+                token: "BREAKING CHANGE".to_string(),
+                value: "the --old flag is removed".to_string(),
+                separator: ':',
+            }]),
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): drop the old flag
+
+BREAKING CHANGE: the --old flag is removed"
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): drop the old flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_misspelled_token() {
+        let rule = BreakingChangeToken::default();
+        let message = Message {
+            body: Some("Breaking Changes: the --old flag is removed".to_string()),
+            description: Some("drop the old flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): drop the old flag
+
+Breaking Changes: the --old flag is removed"
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): drop the old flag".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "breaking change token \"Breaking Changes\" must be one of [\"BREAKING CHANGE\", \"BREAKING-CHANGE\"]"
+                .to_string()
+        );
+    }
+}
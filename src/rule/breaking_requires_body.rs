@@ -0,0 +1,115 @@
+use crate::{message::Message, result::Violation, rule::Rule};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// BreakingRequiresBody represents the breaking-requires-body rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BreakingRequiresBody {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+}
+
+impl BreakingRequiresBody {
+    /// Check whether the breaking change is explained either in the body
+    /// or in the `BREAKING CHANGE` footer.
+    fn is_explained(&self, message: &Message) -> bool {
+        let has_body = message
+            .body
+            .as_deref()
+            .is_some_and(|body| !body.trim().is_empty());
+        let has_footer = message
+            .breaking_footer()
+            .is_some_and(|footer| !footer.value.trim().is_empty());
+
+        has_body || has_footer
+    }
+}
+
+/// BreakingRequiresBody represents the breaking-requires-body rule.
+impl Rule for BreakingRequiresBody {
+    const NAME: &'static str = "breaking-requires-body";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, _message: &Message) -> String {
+        "breaking change must be explained in the body or in \"BREAKING CHANGE\" footer".to_string()
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if message.is_breaking() && !self.is_explained(message) {
+            return Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            });
+        }
+
+        None
+    }
+}
+
+/// Default implementation of BreakingRequiresBody.
+impl Default for BreakingRequiresBody {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_breaking_with_body() {
+        let rule = BreakingRequiresBody::default();
+        let message = Message::new(
+            "feat(cli)!: drop the old flag
+
+The --old flag is replaced by --new."
+                .to_string(),
+        );
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_breaking_with_footer() {
+        let rule = BreakingRequiresBody::default();
+        let message = Message::new(
+            "feat(cli)!: drop the old flag
+
+BREAKING CHANGE: the --old flag is replaced by --new"
+                .to_string(),
+        );
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_breaking_without_body() {
+        let rule = BreakingRequiresBody::default();
+        let message = Message::new("feat(cli)!: drop the old flag".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "breaking change must be explained in the body or in \"BREAKING CHANGE\" footer"
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_not_breaking() {
+        let rule = BreakingRequiresBody::default();
+        let message = Message::new("feat(cli): add new flag".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
+}
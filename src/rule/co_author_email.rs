@@ -0,0 +1,126 @@
+use crate::{
+    message::{Footer, Message},
+    pattern,
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// CoAuthorEmail represents the co-author-email rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CoAuthorEmail {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+}
+
+impl CoAuthorEmail {
+    /// Check whether the co-author is written as `Name <email>` with a plausible email.
+    fn is_valid(footer: &Footer) -> bool {
+        let re = pattern::compile(r"^[^@\s<>]+@[^@\s<>]+\.[^@\s<>.]+$").unwrap();
+
+        footer.name().is_some() && footer.email().is_some_and(|email| re.is_match(email))
+    }
+
+    /// Find the co-authors which are not written as `Name <email>`.
+    fn find_invalid<'a>(&self, message: &'a Message) -> Vec<&'a Footer> {
+        message
+            .co_authors()
+            .into_iter()
+            .filter(|footer| !Self::is_valid(footer))
+            .collect()
+    }
+
+    /// Build the violation of the co-author.
+    fn violation(&self, footer: &Footer) -> Violation {
+        Violation {
+            level: self.level.unwrap_or(Self::LEVEL),
+            message: format!(
+                "co-author {:?} must be in the format of \"Name <email>\"",
+                footer.value
+            ),
+            rule: Self::NAME.to_string(),
+        }
+    }
+}
+
+/// CoAuthorEmail represents the co-author-email rule.
+impl Rule for CoAuthorEmail {
+    const NAME: &'static str = "co-author-email";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        match self.find_invalid(message).first() {
+            Some(footer) => self.violation(footer).message,
+            None => "co-authors must be in the format of \"Name <email>\"".to_string(),
+        }
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        self.validate_all(message).into_iter().next()
+    }
+
+    fn validate_all(&self, message: &Message) -> Vec<Violation> {
+        self.find_invalid(message)
+            .into_iter()
+            .map(|footer| self.violation(footer))
+            .collect()
+    }
+}
+
+/// Default implementation of CoAuthorEmail.
+impl Default for CoAuthorEmail {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_co_author() {
+        let rule = CoAuthorEmail::default();
+        let message = Message::new(
+            "feat(cli): add new flag
+
+Co-authored-by: Keke <keke@example.com>"
+                .to_string(),
+        );
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_co_author_without_email() {
+        let rule = CoAuthorEmail::default();
+        let message = Message::new(
+            "feat(cli): add new flag
+
+Co-authored-by: Keke <keke@example.com>
+Co-authored-by: Jane Doe jane@example.com
+Co-authored-by: Z <z@localhost>"
+                .to_string(),
+        );
+
+        let violations = rule.validate_all(&message);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].level, Level::Error);
+        assert_eq!(
+            violations[0].message,
+            "co-author \"Jane Doe jane@example.com\" must be in the format of \"Name <email>\""
+                .to_string()
+        );
+        assert_eq!(
+            violations[1].message,
+            "co-author \"Z <z@localhost>\" must be in the format of \"Name <email>\"".to_string()
+        );
+    }
+}
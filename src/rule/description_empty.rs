@@ -27,10 +27,12 @@ impl Rule for DescriptionEmpty {
             None => Some(Violation {
                 level: self.level.unwrap_or(Self::LEVEL),
                 message: self.message(message),
+                rule: Self::NAME.to_string(),
             }),
             Some(ref desc) if desc.is_empty() => Some(Violation {
                 level: self.level.unwrap_or(Self::LEVEL),
                 message: self.message(message),
+                rule: Self::NAME.to_string(),
             }),
             _ => None,
         }
@@ -60,6 +62,7 @@ mod tests {
             r#type: Some("feat".to_string()),
             raw: "feat(scope): broadcast $destroy event on scope destruction".to_string(),
             scope: Some("scope".to_string()),
+            separator: ":".to_string(),
             subject: None,
         };
 
@@ -76,6 +79,7 @@ mod tests {
             r#type: Some("feat".to_string()),
             raw: "(scope):".to_string(),
             scope: Some("scope".to_string()),
+            separator: ":".to_string(),
             subject: None,
         };
 
@@ -98,6 +102,7 @@ mod tests {
             r#type: Some("feat".to_string()),
             raw: "(scope):".to_string(),
             scope: Some("scope".to_string()),
+            separator: ":".to_string(),
             subject: None,
         };
 
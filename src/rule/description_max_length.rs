@@ -1,4 +1,4 @@
-use crate::{message::Message, result::Violation, rule::Rule};
+use crate::{message::Message, result::Violation, rule::Rule, width::display_width};
 use serde::{Deserialize, Serialize};
 
 use super::Level;
@@ -27,10 +27,11 @@ impl Rule for DescriptionMaxLength {
 
     fn validate(&self, message: &Message) -> Option<Violation> {
         if let Some(desc) = &message.description {
-            if desc.len() >= self.length {
+            if display_width(desc) >= self.length {
                 return Some(Violation {
                     level: self.level.unwrap_or(Self::LEVEL),
                     message: self.message(message),
+                    rule: Self::NAME.to_string(),
                 });
             }
         }
@@ -66,6 +67,7 @@ mod tests {
             r#type: Some("feat".to_string()),
             raw: "feat(scope): desc".to_string(),
             scope: Some("scope".to_string()),
+            separator: ":".to_string(),
             subject: Some("feat(scope): desc".to_string()),
         };
 
@@ -85,6 +87,7 @@ mod tests {
             r#type: Some("feat".to_string()),
             raw: "feat(scope)".to_string(),
             scope: Some("scope".to_string()),
+            separator: ":".to_string(),
             subject: None,
         };
 
@@ -104,6 +107,7 @@ mod tests {
             r#type: Some("feat".to_string()),
             raw: "feat(scope): I'm long description".to_string(),
             scope: Some("scope".to_string()),
+            separator: ":".to_string(),
             subject: None,
         };
 
@@ -0,0 +1,179 @@
+use crate::{
+    case::{self, Case},
+    message::{Footer, Message},
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// FooterCase represents the footer-case rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FooterCase {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Allowed represents the allowed cases of the footer tokens.
+    /// Available cases are the same as the subject-case rule.
+    #[serde(deserialize_with = "case::deserialize_names")]
+    allowed: Vec<String>,
+}
+
+impl FooterCase {
+    /// Find the footers whose token is not in the allowed cases.
+    /// Note that `BREAKING CHANGE` must be uppercase as described in the specification,
+    /// so that it is always allowed.
+    fn find_invalid<'a>(&self, message: &'a Message) -> Vec<&'a Footer> {
+        message
+            .footers
+            .iter()
+            .flatten()
+            .filter(|footer| footer.token != "BREAKING CHANGE" && footer.token != "BREAKING-CHANGE")
+            .filter(|footer| {
+                !self
+                    .allowed
+                    .iter()
+                    .filter_map(|name| Case::from_name(name))
+                    .any(|case| case.matches(&footer.token))
+            })
+            .collect()
+    }
+
+    /// Build the violation of the footer token.
+    fn violation(&self, footer: &Footer) -> Violation {
+        Violation {
+            level: self.level.unwrap_or(Self::LEVEL),
+            message: format!(
+                "footer token {} must be in one of {:?}",
+                footer.token, self.allowed
+            ),
+            rule: Self::NAME.to_string(),
+        }
+    }
+}
+
+/// FooterCase represents the footer-case rule.
+impl Rule for FooterCase {
+    const NAME: &'static str = "footer-case";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        match self.find_invalid(message).first() {
+            Some(footer) => self.violation(footer).message,
+            None => format!("footer tokens must be in one of {:?}", self.allowed),
+        }
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        self.validate_all(message).into_iter().next()
+    }
+
+    fn validate_all(&self, message: &Message) -> Vec<Violation> {
+        self.find_invalid(message)
+            .into_iter()
+            .map(|footer| self.violation(footer))
+            .collect()
+    }
+}
+
+/// Default implementation of FooterCase.
+impl Default for FooterCase {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            allowed: vec!["sentence-case".to_string()],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_footers() {
+        let rule = FooterCase::default();
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: Some(vec![
+                Footer {
+                    token: "Reviewed-by".to_string(),
+                    value: "Z".to_string(),
+                    separator: ':',
+                },
+                Footer {
+                    token: "BREAKING CHANGE".to_string(),
+                    value: "the option is removed".to_string(),
+                    separator: ':',
+                },
+            ]),
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag
+
+Reviewed-by: Z
+BREAKING CHANGE: the option is removed"
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_invalid_footers() {
+        let rule = FooterCase {
+            allowed: vec!["train-case".to_string()],
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: Some(vec![
+                Footer {
+                    token: "Reviewed-By".to_string(),
+                    value: "Z".to_string(),
+                    separator: ':',
+                },
+                Footer {
+                    token: "signed-off-by".to_string(),
+                    value: "Z <z@example.com>".to_string(),
+                    separator: ':',
+                },
+                Footer {
+                    token: "refs".to_string(),
+                    value: "133".to_string(),
+                    separator: '#',
+                },
+            ]),
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag
+
+Reviewed-By: Z
+signed-off-by: Z <z@example.com>
+refs #133"
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        let violations = rule.validate_all(&message);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].level, Level::Error);
+        assert_eq!(
+            violations[0].message,
+            "footer token signed-off-by must be in one of [\"train-case\"]".to_string()
+        );
+        assert_eq!(
+            violations[1].message,
+            "footer token refs must be in one of [\"train-case\"]".to_string()
+        );
+    }
+}
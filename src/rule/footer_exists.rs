@@ -0,0 +1,168 @@
+use crate::{message::Message, result::Violation, rule::Rule};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// FooterExists represents the footer-exists rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FooterExists {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Required represents the footer tokens that must exist.
+    /// Note that the tokens are compared case-insensitively.
+    required: Vec<String>,
+}
+
+impl FooterExists {
+    /// Find the required footer tokens which are missing in the message.
+    fn missing(&self, message: &Message) -> Vec<String> {
+        let tokens: Vec<String> = message
+            .footers
+            .iter()
+            .flatten()
+            .map(|footer| footer.token.to_lowercase())
+            .collect();
+
+        self.required
+            .iter()
+            .filter(|required| !tokens.contains(&required.to_lowercase()))
+            .cloned()
+            .collect()
+    }
+}
+
+/// FooterExists represents the footer-exists rule.
+impl Rule for FooterExists {
+    const NAME: &'static str = "footer-exists";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        format!("footers {:?} are missing", self.missing(message))
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if !self.missing(message).is_empty() {
+            return Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            });
+        }
+
+        None
+    }
+}
+
+/// Default implementation of FooterExists.
+impl Default for FooterExists {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            required: vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message::Footer;
+
+    #[test]
+    fn test_existing_footers() {
+        let rule = FooterExists {
+            required: vec!["Signed-off-by".to_string(), "reviewed-by".to_string()],
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: Some(vec![
+                Footer {
+                    token: "Signed-off-by".to_string(),
+                    value: "Keke <keke@example.com>".to_string(),
+                    separator: ':',
+                },
+                Footer {
+                    token: "Reviewed-by".to_string(),
+                    value: "Z <z@example.com>".to_string(),
+                    separator: ':',
+                },
+            ]),
+            r#type: Some("feat".to_string()),
+            raw: "feat(scope): add new flag
+
+Signed-off-by: Keke <keke@example.com>
+Reviewed-by: Z <z@example.com>"
+                .to_string(),
+            scope: Some("scope".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(scope): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_partially_missing_footers() {
+        let rule = FooterExists {
+            required: vec!["Signed-off-by".to_string(), "Reviewed-by".to_string()],
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: Some(vec![Footer {
+                token: "Signed-off-by".to_string(),
+                value: "Keke <keke@example.com>".to_string(),
+                separator: ':',
+            }]),
+            r#type: Some("feat".to_string()),
+            raw: "feat(scope): add new flag
+
+Signed-off-by: Keke <keke@example.com>"
+                .to_string(),
+            scope: Some("scope".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(scope): add new flag".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "footers [\"Reviewed-by\"] are missing".to_string()
+        );
+    }
+
+    #[test]
+    fn test_no_footers() {
+        let rule = FooterExists {
+            required: vec!["Signed-off-by".to_string(), "Reviewed-by".to_string()],
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(scope): add new flag".to_string(),
+            scope: Some("scope".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(scope): add new flag".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "footers [\"Signed-off-by\", \"Reviewed-by\"] are missing".to_string()
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use crate::{git::parse_footer_line, message::Message, result::Violation, rule::Rule};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// FooterLeadingBlank represents the footer-leading-blank rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FooterLeadingBlank {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+}
+
+/// FooterLeadingBlank represents the footer-leading-blank rule.
+impl Rule for FooterLeadingBlank {
+    const NAME: &'static str = "footer-leading-blank";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, _message: &Message) -> String {
+        "footer must have a leading blank line".to_string()
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        let lines: Vec<&str> = message
+            .raw
+            .lines()
+            .skip_while(|line| line.trim().is_empty())
+            .skip(1) // Skip the header.
+            .collect();
+
+        // The footers are expected to start the last paragraph.
+        // A footer found in the middle of it directly follows the body text.
+        let last_paragraph = lines
+            .iter()
+            .rposition(|line| line.trim().is_empty())
+            .map_or(0, |i| i + 1);
+
+        let found = lines[last_paragraph..]
+            .iter()
+            .skip(1)
+            .any(|line| parse_footer_line(line).is_some());
+
+        if found {
+            return Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            });
+        }
+
+        None
+    }
+}
+
+/// Default implementation of FooterLeadingBlank.
+impl Default for FooterLeadingBlank {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message::Footer;
+
+    #[test]
+    fn test_leading_blank() {
+        let rule = FooterLeadingBlank::default();
+        let message = Message {
+            body: Some("Hello world".to_string()),
+            description: Some("add new flag".to_string()),
+            footers: Some(vec![Footer {
+                token: "Refs".to_string(),
+                value: "133".to_string(),
+                separator: '#',
+            }]),
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag
+
+Hello world
+
+Refs #133"
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_no_leading_blank() {
+        let rule = FooterLeadingBlank::default();
+        let message = Message {
+            body: Some("Hello world\nRefs #133".to_string()),
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag
+
+Hello world
+Refs #133"
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "footer must have a leading blank line".to_string()
+        );
+    }
+
+    #[test]
+    fn test_body_only() {
+        let rule = FooterLeadingBlank::default();
+        let message = Message {
+            body: Some("Hello world\nHow are you?".to_string()),
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag
+
+Hello world
+How are you?"
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+}
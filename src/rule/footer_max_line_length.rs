@@ -0,0 +1,234 @@
+use crate::{message::Message, result::Violation, rule::Rule, width::display_width};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// FooterMaxLineLength represents the footer-max-line-length rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FooterMaxLineLength {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Length represents the maximum length of each line of the footers.
+    length: usize,
+
+    /// IgnoreUrls represents whether the lines only containing an URL are ignored.
+    /// The first line of a footer is ignored if its value only contains an URL, e.g. `Refs: https://...`.
+    #[serde(default)]
+    ignore_urls: bool,
+}
+
+impl FooterMaxLineLength {
+    /// Find the first line of the footers longer than the maximum length.
+    /// Returns the line number in the footers (starting from 1) and the length of the line.
+    fn find_long_line(&self, message: &Message) -> Option<(usize, usize)> {
+        let footers = message.footers.as_ref()?;
+        let lines: Vec<(String, bool)> = footers
+            .iter()
+            .flat_map(|footer| {
+                // The first line starts with the token, so that only the value is checked.
+                let value = footer.value.lines().next().unwrap_or_default();
+                footer
+                    .to_string()
+                    .lines()
+                    .enumerate()
+                    .map(|(i, line)| {
+                        let url = if i == 0 { is_url(value) } else { is_url(line) };
+                        (line.to_string(), url)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        lines
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, url))| !(self.ignore_urls && *url))
+            .map(|(i, (line, _))| (i + 1, display_width(line)))
+            .find(|(_, length)| *length > self.length)
+    }
+}
+
+/// Check whether the line only contains an URL as the body-max-line-length rule.
+fn is_url(line: &str) -> bool {
+    let line = line.trim();
+    (line.starts_with("http://") || line.starts_with("https://"))
+        && !line.contains(char::is_whitespace)
+}
+
+/// FooterMaxLineLength represents the footer-max-line-length rule.
+impl Rule for FooterMaxLineLength {
+    const NAME: &'static str = "footer-max-line-length";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        match self.find_long_line(message) {
+            Some((line, length)) => format!(
+                "footer line {} is {} characters, longer than {} characters",
+                line, length, self.length
+            ),
+            None => format!("footer line is longer than {} characters", self.length),
+        }
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if self.find_long_line(message).is_some() {
+            return Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            });
+        }
+
+        None
+    }
+}
+
+/// Default implementation of FooterMaxLineLength.
+impl Default for FooterMaxLineLength {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            length: 100,
+            ignore_urls: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message::Footer;
+
+    #[test]
+    fn test_short_lines() {
+        let rule = FooterMaxLineLength {
+            length: 20,
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: Some(vec![Footer {
+                token: "Refs".to_string(),
+                value: "133".to_string(),
+                separator: '#',
+            }]),
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag
+
+Refs #133"
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_long_line() {
+        let rule = FooterMaxLineLength {
+            length: 20,
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: Some(vec![
+                Footer {
+                    token: "Refs".to_string(),
+                    value: "133".to_string(),
+                    separator: '#',
+                },
+                Footer {
+                    token: "BREAKING CHANGE".to_string(),
+                    value: "the option is removed".to_string(),
+                    separator: ':',
+                },
+            ]),
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag
+
+Refs #133
+BREAKING CHANGE: the option is removed"
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "footer line 2 is 38 characters, longer than 20 characters".to_string()
+        );
+    }
+
+    #[test]
+    fn test_ignore_urls() {
+        let rule = FooterMaxLineLength {
+            length: 20,
+            ignore_urls: true,
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: Some(vec![Footer {
+                token: "See".to_string(),
+                value: "https://github.com/KeisukeYamashita/commitlint-rs/issues".to_string(),
+                separator: ':',
+            }]),
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag
+
+See: https://github.com/KeisukeYamashita/commitlint-rs/issues"
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_long_line_with_short_url() {
+        let rule = FooterMaxLineLength {
+            length: 20,
+            ignore_urls: true,
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: Some(vec![Footer {
+                token: "BREAKING CHANGE".to_string(),
+                value: "the option is removed, see https://x.io".to_string(),
+                separator: ':',
+            }]),
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag
+
+BREAKING CHANGE: the option is removed, see https://x.io"
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(
+            violation.unwrap().message,
+            "footer line 1 is 56 characters, longer than 20 characters".to_string()
+        );
+    }
+}
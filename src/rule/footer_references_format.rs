@@ -0,0 +1,227 @@
+use crate::{
+    message::{Footer, Message},
+    pattern,
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// FooterReferencesFormat represents the footer-references-format rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FooterReferencesFormat {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Tokens represents the tokens of the footers containing the references.
+    /// The tokens are compared case-insensitively.
+    #[serde(default = "default_tokens")]
+    tokens: Vec<String>,
+
+    /// Pattern represents the regex which each reference must match.
+    #[serde(default = "default_pattern")]
+    pattern: String,
+}
+
+/// Tokens of the footers referencing the issues.
+fn default_tokens() -> Vec<String> {
+    ["Closes", "Fixes", "Refs"]
+        .iter()
+        .map(|t| t.to_string())
+        .collect()
+}
+
+/// Default regex matching references such as `#123` and `JIRA-4`.
+fn default_pattern() -> String {
+    r"^(?:#\d+|[A-Z][A-Z0-9]+-\d+)$".to_string()
+}
+
+impl FooterReferencesFormat {
+    /// Check whether the footer contains the references.
+    fn is_reference_footer(&self, footer: &Footer) -> bool {
+        self.tokens
+            .iter()
+            .any(|token| token.eq_ignore_ascii_case(&footer.token))
+    }
+
+    /// Get the references of the footer separated by commas or whitespaces.
+    /// The `#` separator such as `Refs #123` is a part of the first reference.
+    fn references(footer: &Footer) -> Vec<String> {
+        let value = match footer.separator {
+            '#' => format!("#{}", footer.value),
+            _ => footer.value.clone(),
+        };
+
+        value
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|reference| !reference.is_empty())
+            .map(|reference| reference.to_string())
+            .collect()
+    }
+
+    /// Build the violation with the message.
+    fn violation(&self, message: String) -> Violation {
+        Violation {
+            level: self.level.unwrap_or(Self::LEVEL),
+            message,
+            rule: Self::NAME.to_string(),
+        }
+    }
+}
+
+/// FooterReferencesFormat represents the footer-references-format rule.
+impl Rule for FooterReferencesFormat {
+    const NAME: &'static str = "footer-references-format";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        match self.validate(message) {
+            Some(violation) => violation.message,
+            None => format!("references must match {:?}", self.pattern),
+        }
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        self.validate_all(message).into_iter().next()
+    }
+
+    fn validate_all(&self, message: &Message) -> Vec<Violation> {
+        let regex = match pattern::compile(&self.pattern) {
+            Ok(regex) => regex,
+            Err(err) => return vec![self.violation(err.to_string())],
+        };
+
+        message
+            .footers
+            .iter()
+            .flatten()
+            .filter(|footer| self.is_reference_footer(footer))
+            .flat_map(|footer| {
+                Self::references(footer)
+                    .into_iter()
+                    .filter(|reference| !regex.is_match(reference))
+                    .map(|reference| {
+                        self.violation(format!(
+                            "reference {:?} in footer {} must match {:?}",
+                            reference, footer.token, self.pattern
+                        ))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
+
+/// Default implementation of FooterReferencesFormat.
+impl Default for FooterReferencesFormat {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            tokens: default_tokens(),
+            pattern: default_pattern(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_reference() {
+        let rule = FooterReferencesFormat::default();
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: Some(vec![Footer {
+                token: "Closes".to_string(),
+                value: "12".to_string(),
+                separator: '#',
+            }]),
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag
+
+Closes #12"
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        assert!(rule.validate_all(&message).is_empty());
+    }
+
+    #[test]
+    fn test_malformed_reference() {
+        let rule = FooterReferencesFormat::default();
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: Some(vec![
+                Footer {
+                    token: "Closes".to_string(),
+                    value: "banana".to_string(),
+                    separator: ':',
+                },
+                Footer {
+                    token: "Reviewed-by".to_string(),
+                    value: "Alice".to_string(),
+                    separator: ':',
+                },
+            ]),
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag
+
+Closes: banana
+Reviewed-by: Alice"
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        let violations = rule.validate_all(&message);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].level, Level::Error);
+        assert_eq!(
+            violations[0].message,
+            "reference \"banana\" in footer Closes must match \"^(?:#\\\\d+|[A-Z][A-Z0-9]+-\\\\d+)$\""
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_multiple_references() {
+        let rule = FooterReferencesFormat::default();
+        let message = Message::new(
+            "feat(cli): add new flag
+
+Refs #12, JIRA-4, 13"
+                .to_string(),
+        );
+
+        let violations = rule.validate_all(&message);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.starts_with("reference \"13\""));
+    }
+
+    #[test]
+    fn test_invalid_pattern() {
+        let rule = FooterReferencesFormat {
+            pattern: "(".to_string(),
+            ..Default::default()
+        };
+        let message = Message::new(
+            "feat(cli): add new flag
+
+Closes #12"
+                .to_string(),
+        );
+
+        assert!(rule.validate(&message).is_some());
+    }
+}
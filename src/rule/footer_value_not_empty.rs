@@ -0,0 +1,116 @@
+use crate::{
+    message::{Footer, Message},
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// FooterValueNotEmpty represents the footer-value-not-empty rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FooterValueNotEmpty {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+}
+
+impl FooterValueNotEmpty {
+    /// Find the footers without the value such as `Reviewed-by:`.
+    fn find_empty<'a>(&self, message: &'a Message) -> Vec<&'a Footer> {
+        message
+            .footers
+            .iter()
+            .flatten()
+            .filter(|footer| footer.value.trim().is_empty())
+            .collect()
+    }
+
+    /// Build the violation of the footer token.
+    fn violation(&self, footer: &Footer) -> Violation {
+        Violation {
+            level: self.level.unwrap_or(Self::LEVEL),
+            message: format!("footer {} must not be empty", footer.token),
+            rule: Self::NAME.to_string(),
+        }
+    }
+}
+
+/// FooterValueNotEmpty represents the footer-value-not-empty rule.
+impl Rule for FooterValueNotEmpty {
+    const NAME: &'static str = "footer-value-not-empty";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        match self.find_empty(message).first() {
+            Some(footer) => self.violation(footer).message,
+            None => "footers must not be empty".to_string(),
+        }
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        self.validate_all(message).into_iter().next()
+    }
+
+    fn validate_all(&self, message: &Message) -> Vec<Violation> {
+        self.find_empty(message)
+            .into_iter()
+            .map(|footer| self.violation(footer))
+            .collect()
+    }
+}
+
+/// Default implementation of FooterValueNotEmpty.
+impl Default for FooterValueNotEmpty {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filled_footers() {
+        let rule = FooterValueNotEmpty::default();
+        let message = Message::new(
+            "feat(cli): add new flag
+
+Reviewed-by: Z
+Refs #133"
+                .to_string(),
+        );
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_empty_footers() {
+        let rule = FooterValueNotEmpty::default();
+        let message = Message::new(
+            "feat(cli): add new flag
+
+Reviewed-by:
+Signed-off-by: Z <z@example.com>
+Acked-by:"
+                .to_string(),
+        );
+
+        let violations = rule.validate_all(&message);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].level, Level::Error);
+        assert_eq!(
+            violations[0].message,
+            "footer Reviewed-by must not be empty".to_string()
+        );
+        assert_eq!(
+            violations[1].message,
+            "footer Acked-by must not be empty".to_string()
+        );
+    }
+}
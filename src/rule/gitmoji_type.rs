@@ -0,0 +1,274 @@
+use crate::{emoji, message::Message, result::Violation, rule::Rule};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// GitmojiType represents the gitmoji-type rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GitmojiType {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Gitmojis represents the allowed gitmojis.
+    /// Defaults to the common gitmojis mapped to the types of the conventional commits.
+    #[serde(default = "default_gitmojis")]
+    gitmojis: Vec<Gitmoji>,
+}
+
+/// Gitmoji represents an allowed gitmoji.
+/// See: https://gitmoji.dev
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Gitmoji {
+    /// Emoji represents the unicode emoji such as `✨`.
+    emoji: String,
+
+    /// Code represents the shortcode such as `:sparkles:`.
+    code: String,
+
+    /// Type represents the conventional type the gitmoji must be used with.
+    /// If it is not specified, the gitmoji can be used with any type.
+    #[serde(default)]
+    r#type: Option<String>,
+}
+
+impl Gitmoji {
+    /// Check whether the found emoji or shortcode is the gitmoji.
+    /// The variation selector is ignored, so that both `⚡` and `⚡️` match.
+    fn matches(&self, found: &str) -> bool {
+        let strip = |text: &str| text.replace('\u{FE0F}', "");
+        found == self.code || strip(found) == strip(&self.emoji)
+    }
+}
+
+/// Common gitmojis mapped to the types of the conventional commits.
+fn default_gitmojis() -> Vec<Gitmoji> {
+    [
+        ("✨", ":sparkles:", "feat"),
+        ("🐛", ":bug:", "fix"),
+        ("📝", ":memo:", "docs"),
+        ("🎨", ":art:", "style"),
+        ("♻️", ":recycle:", "refactor"),
+        ("⚡️", ":zap:", "perf"),
+        ("✅", ":white_check_mark:", "test"),
+        ("📦️", ":package:", "build"),
+        ("👷", ":construction_worker:", "ci"),
+        ("🔧", ":wrench:", "chore"),
+        ("⏪️", ":rewind:", "revert"),
+    ]
+    .iter()
+    .map(|(emoji, code, r#type)| Gitmoji {
+        emoji: emoji.to_string(),
+        code: code.to_string(),
+        r#type: Some(r#type.to_string()),
+    })
+    .collect()
+}
+
+/// Find the leading gitmoji of the text either in the unicode or the shortcode form.
+fn leading_gitmoji(text: &str) -> Option<String> {
+    let word = text.split_whitespace().next()?;
+
+    if let Some(rest) = word.strip_prefix(':') {
+        let end = rest.find(':')?;
+        return Some(word[..end + 2].to_string());
+    }
+
+    if !word.chars().next().is_some_and(emoji::is_emoji) {
+        return None;
+    }
+
+    emoji::find(word).into_iter().next()
+}
+
+impl GitmojiType {
+    /// Get the problem of the leading gitmoji if any.
+    fn problem(&self, message: &Message) -> Option<String> {
+        let allowed: Vec<&str> = self.gitmojis.iter().map(|g| g.emoji.as_str()).collect();
+
+        let found = match message.description.as_deref().and_then(leading_gitmoji) {
+            Some(found) => found,
+            None => {
+                return Some(format!(
+                    "subject must start with a gitmoji. Only {:?} are allowed",
+                    allowed
+                ))
+            }
+        };
+
+        let gitmoji = match self.gitmojis.iter().find(|g| g.matches(&found)) {
+            Some(gitmoji) => gitmoji,
+            None => {
+                return Some(format!(
+                    "gitmoji {} is not allowed. Only {:?} are allowed",
+                    found, allowed
+                ))
+            }
+        };
+
+        match (&gitmoji.r#type, &message.r#type) {
+            (Some(expected), Some(r#type)) if expected != r#type => Some(format!(
+                "gitmoji {} must be used with type {} but found {}",
+                gitmoji.emoji, expected, r#type
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// GitmojiType represents the gitmoji-type rule.
+impl Rule for GitmojiType {
+    const NAME: &'static str = "gitmoji-type";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        self.problem(message).unwrap_or_else(|| {
+            let allowed: Vec<&str> = self.gitmojis.iter().map(|g| g.emoji.as_str()).collect();
+            format!("gitmoji must be in one of {:?}", allowed)
+        })
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if self.problem(message).is_some() {
+            return Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            });
+        }
+
+        None
+    }
+}
+
+/// Default implementation of GitmojiType.
+impl Default for GitmojiType {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            gitmojis: default_gitmojis(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allowed_emoji() {
+        let rule = GitmojiType::default();
+        let message = Message {
+            body: None,
+            description: Some("✨ add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): ✨ add new flag".to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): ✨ add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_allowed_shortcode() {
+        let rule = GitmojiType::default();
+        let message = Message {
+            body: None,
+            description: Some(":zap: speed up the parser".to_string()),
+            footers: None,
+            r#type: None,
+            raw: ":zap: speed up the parser".to_string(),
+            scope: None,
+            separator: ":".to_string(),
+            subject: Some(":zap: speed up the parser".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_unknown_gitmoji() {
+        let rule = GitmojiType {
+            gitmojis: vec![Gitmoji {
+                emoji: "✨".to_string(),
+                code: ":sparkles:".to_string(),
+                r#type: None,
+            }],
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: Some("🦄 add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): 🦄 add new flag".to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): 🦄 add new flag".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "gitmoji 🦄 is not allowed. Only [\"✨\"] are allowed".to_string()
+        );
+    }
+
+    #[test]
+    fn test_missing_gitmoji() {
+        let rule = GitmojiType {
+            gitmojis: vec![Gitmoji {
+                emoji: "✨".to_string(),
+                code: ":sparkles:".to_string(),
+                r#type: None,
+            }],
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag".to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(
+            violation.unwrap().message,
+            "subject must start with a gitmoji. Only [\"✨\"] are allowed".to_string()
+        );
+    }
+
+    #[test]
+    fn test_mismatched_type() {
+        let rule = GitmojiType::default();
+        let message = Message {
+            body: None,
+            description: Some(":bug: add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): :bug: add new flag".to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): :bug: add new flag".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(
+            violation.unwrap().message,
+            "gitmoji 🐛 must be used with type fix but found feat".to_string()
+        );
+    }
+}
@@ -0,0 +1,271 @@
+use crate::{message::Message, result::Violation, rule::Rule, width::display_width};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// HeaderMaxLength represents the header-max-length rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct HeaderMaxLength {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Length represents the maximum length of the header.
+    #[serde(default = "default_length")]
+    length: usize,
+
+    /// ExcludePrefix represents whether the `type(scope): ` prefix is excluded from the length.
+    /// The whole header is measured if it does not follow the conventional commits format.
+    #[serde(default)]
+    exclude_prefix: bool,
+}
+
+/// Headers are limited to 100 characters by default.
+fn default_length() -> usize {
+    100
+}
+
+impl HeaderMaxLength {
+    /// Get the length of the header to compare with the maximum length.
+    fn measure(&self, message: &Message) -> usize {
+        let header = header(message);
+        if !(self.exclude_prefix && message.conforms()) {
+            return display_width(header);
+        }
+
+        match header.split_once(message.separator.as_str()) {
+            Some((_, description)) => display_width(description.trim_start()),
+            None => display_width(header),
+        }
+    }
+}
+
+/// Get the header of the commit message.
+/// Note that the leading blank lines are skipped.
+pub(crate) fn header(message: &Message) -> &str {
+    message
+        .raw
+        .lines()
+        .find(|line| !line.trim().is_empty())
+        .unwrap_or("")
+}
+
+/// HeaderMaxLength represents the header-max-length rule.
+impl Rule for HeaderMaxLength {
+    const NAME: &'static str = "header-max-length";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        if self.exclude_prefix {
+            return format!(
+                "header is longer than {} characters excluding the type prefix (found {})",
+                self.length,
+                self.measure(message)
+            );
+        }
+
+        format!(
+            "header is longer than {} characters (found {})",
+            self.length,
+            self.measure(message)
+        )
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if self.measure(message) > self.length {
+            return Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            });
+        }
+
+        None
+    }
+}
+
+/// Default implementation of HeaderMaxLength.
+impl Default for HeaderMaxLength {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            length: default_length(),
+            exclude_prefix: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_length() {
+        let rule: HeaderMaxLength = serde_yaml::from_str("level: error").unwrap();
+        assert_eq!(rule.length, 100);
+
+        let message = Message::new(format!("feat: {}", "a".repeat(94)));
+        assert!(rule.validate(&message).is_none());
+
+        let message = Message::new(format!("feat: {}", "a".repeat(95)));
+        assert_eq!(
+            rule.validate(&message).unwrap().message,
+            "header is longer than 100 characters (found 101)".to_string()
+        );
+    }
+
+    #[test]
+    fn test_header_at_limit() {
+        let rule = HeaderMaxLength {
+            length: 23, // Exactly the length of the header
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag".to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_long_header() {
+        let rule = HeaderMaxLength {
+            length: 22,
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag".to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "header is longer than 22 characters (found 23)".to_string()
+        );
+    }
+
+    #[test]
+    fn test_leading_blank_lines() {
+        let rule = HeaderMaxLength {
+            length: 22,
+            ..Default::default()
+        };
+        let message = Message {
+            body: Some("feat(cli): add new flag".to_string()),
+            description: Some("".to_string()),
+            footers: None,
+            r#type: None,
+            raw: "
+
+feat(cli): add new flag"
+                .to_string(),
+            scope: None,
+            separator: ":".to_string(),
+            subject: Some("".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(
+            violation.unwrap().message,
+            "header is longer than 22 characters (found 23)".to_string()
+        );
+    }
+
+    #[test]
+    fn test_wide_characters() {
+        let rule = HeaderMaxLength {
+            length: 16,
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: Some("添加新标志".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat: 添加新标志".to_string(),
+            scope: None,
+            separator: ":".to_string(),
+            subject: Some("feat: 添加新标志".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_exclude_prefix() {
+        let message = Message::new("feat(cli): add new flag".to_string());
+
+        let rule = HeaderMaxLength {
+            length: 12,
+            ..Default::default()
+        };
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(
+            violation.unwrap().message,
+            "header is longer than 12 characters (found 23)".to_string()
+        );
+
+        let rule = HeaderMaxLength {
+            length: 12,
+            exclude_prefix: true,
+            ..Default::default()
+        };
+        assert!(rule.validate(&message).is_none());
+
+        let rule = HeaderMaxLength {
+            length: 11,
+            exclude_prefix: true,
+            ..Default::default()
+        };
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(
+            violation.unwrap().message,
+            "header is longer than 11 characters excluding the type prefix (found 12)".to_string()
+        );
+    }
+
+    #[test]
+    fn test_exclude_prefix_free_form_header() {
+        let rule = HeaderMaxLength {
+            length: 12,
+            exclude_prefix: true,
+            ..Default::default()
+        };
+        let message = Message::new("Add new flag: cli".to_string());
+
+        assert!(rule.validate(&message).is_some());
+    }
+
+    #[test]
+    fn test_exclude_prefix_with_separator() {
+        let rule = HeaderMaxLength {
+            length: 12,
+            exclude_prefix: true,
+            ..Default::default()
+        };
+        let message = Message::with_separator("feat(cli:x)/ add new flag".to_string(), "/");
+
+        assert!(rule.validate(&message).is_none());
+    }
+}
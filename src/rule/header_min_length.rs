@@ -0,0 +1,94 @@
+use crate::{message::Message, result::Violation, rule::Rule, width::display_width};
+use serde::{Deserialize, Serialize};
+
+use super::{header_max_length::header, Level};
+
+/// HeaderMinLength represents the header-min-length rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct HeaderMinLength {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Length represents the minimum length of the header.
+    length: usize,
+}
+
+/// HeaderMinLength represents the header-min-length rule.
+impl Rule for HeaderMinLength {
+    const NAME: &'static str = "header-min-length";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        format!(
+            "header is shorter than {} characters (found {})",
+            self.length,
+            display_width(header(message))
+        )
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        // Note that the empty header is checked by the subject-empty rule.
+        let header = header(message);
+        if header.trim().is_empty() {
+            return None;
+        }
+
+        if display_width(header) < self.length {
+            return Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            });
+        }
+
+        None
+    }
+}
+
+/// Default implementation of HeaderMinLength.
+impl Default for HeaderMinLength {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            length: 10,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_long_header() {
+        let rule = HeaderMinLength::default();
+        let message = Message::new("feat(cli): add new flag".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_short_header() {
+        let rule = HeaderMinLength::default();
+        let message = Message::new("fix: b".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "header is shorter than 10 characters (found 6)".to_string()
+        );
+    }
+
+    #[test]
+    fn test_empty_header() {
+        let rule = HeaderMinLength::default();
+        let message = Message::new("".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
+}
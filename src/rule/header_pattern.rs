@@ -0,0 +1,121 @@
+use crate::{message::Message, pattern, result::Violation, rule::Rule};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// HeaderPattern represents the header-pattern rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct HeaderPattern {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Pattern represents the regular expression the header must match.
+    pattern: String,
+}
+
+/// HeaderPattern represents the header-pattern rule.
+impl Rule for HeaderPattern {
+    const NAME: &'static str = "header-pattern";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, _message: &Message) -> String {
+        format!("header does not match pattern: {}", self.pattern)
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        let regex = match pattern::compile(&self.pattern) {
+            Ok(regex) => regex,
+            Err(err) => {
+                return Some(Violation {
+                    level: self.level.unwrap_or(Self::LEVEL),
+                    message: err.to_string(),
+                    rule: Self::NAME.to_string(),
+                });
+            }
+        };
+
+        let header = message.raw.lines().next().unwrap_or_default();
+        if !regex.is_match(header) {
+            return Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            });
+        }
+
+        None
+    }
+}
+
+/// Default implementation of HeaderPattern.
+impl Default for HeaderPattern {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            pattern: r"^\w+(\([^()]+\))?!?: .+$".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matching_header() {
+        let rule = HeaderPattern {
+            pattern: r"^\[[A-Z]+-\d+\] .+$".to_string(),
+            ..Default::default()
+        };
+
+        let message = Message::new("[PROJ-123] Add new flag\n\nHello world".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_non_matching_header() {
+        let rule = HeaderPattern {
+            pattern: r"^\[[A-Z]+-\d+\] .+$".to_string(),
+            ..Default::default()
+        };
+
+        let message = Message::new("feat(scope): add new flag".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            r"header does not match pattern: ^\[[A-Z]+-\d+\] .+$".to_string()
+        );
+    }
+
+    #[test]
+    fn test_only_first_line() {
+        let rule = HeaderPattern::default();
+
+        let message = Message::new("add new flag\n\nfeat: add new flag".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+    }
+
+    #[test]
+    fn test_invalid_regex() {
+        let rule = HeaderPattern {
+            pattern: r"(".to_string(),
+            ..Default::default()
+        };
+
+        let message = Message::new("feat(scope): add new flag".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert!(violation.unwrap().message.contains("regex parse error"));
+    }
+}
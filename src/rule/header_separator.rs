@@ -0,0 +1,138 @@
+use crate::{message::Message, pattern, result::Violation, rule::Rule};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// HeaderSeparator represents the header-separator rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct HeaderSeparator {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+}
+
+/// Find the separator between the `type(scope)` prefix and the description
+/// such as `:` of `feat:x` or ` : ` of `feat : x`.
+/// Returns `None` if the header has no prefix.
+fn find_separator(message: &Message) -> Option<&str> {
+    let re = pattern::compile(&format!(
+        r"^\w+(?:\([^\)]*\))?!?(?P<separator>\s*{}\s*)",
+        regex::escape(&message.separator)
+    ))
+    .unwrap();
+
+    re.captures(message.subject.as_deref()?)
+        .and_then(|captures| captures.name("separator"))
+        .map(|separator| separator.as_str())
+}
+
+/// Get the expected separator, which is the configured one followed by a single space.
+fn expected(message: &Message) -> String {
+    format!("{} ", message.separator)
+}
+
+/// HeaderSeparator represents the header-separator rule.
+impl Rule for HeaderSeparator {
+    const NAME: &'static str = "header-separator";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        format!(
+            "header must separate the type and the description with {:?} (found {:?})",
+            expected(message),
+            find_separator(message).unwrap_or_default()
+        )
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        match find_separator(message) {
+            Some(separator) if separator != expected(message) => Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Default implementation of HeaderSeparator.
+impl Default for HeaderSeparator {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_colon_space_separator() {
+        let rule = HeaderSeparator::default();
+
+        assert!(rule
+            .validate(&Message::new("feat: add new flag".to_string()))
+            .is_none());
+        assert!(rule
+            .validate(&Message::new("feat(cli)!: add new flag".to_string()))
+            .is_none());
+        assert!(rule
+            .validate(&Message::new("add new flag".to_string()))
+            .is_none());
+    }
+
+    #[test]
+    fn test_missing_space() {
+        let rule = HeaderSeparator::default();
+        let message = Message::new("feat:add new flag".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "header must separate the type and the description with \": \" (found \":\")"
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_space_before_colon() {
+        let rule = HeaderSeparator::default();
+        let message = Message::new("feat : add new flag".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(
+            violation.unwrap().message,
+            "header must separate the type and the description with \": \" (found \" : \")"
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_custom_separator() {
+        let rule = HeaderSeparator::default();
+
+        assert!(rule
+            .validate(&Message::with_separator(
+                "feat(cli)/ add new flag".to_string(),
+                "/"
+            ))
+            .is_none());
+
+        let message = Message::with_separator("feat(cli) /add new flag".to_string(), "/");
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(
+            violation.unwrap().message,
+            "header must separate the type and the description with \"/ \" (found \" /\")"
+                .to_string()
+        );
+    }
+}
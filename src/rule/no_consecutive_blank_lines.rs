@@ -0,0 +1,179 @@
+use crate::{message::Message, result::Violation, rule::Rule};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// NoConsecutiveBlankLines represents the no-consecutive-blank-lines rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NoConsecutiveBlankLines {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Max represents the maximum number of the consecutive blank lines.
+    #[serde(default = "default_max")]
+    max: usize,
+}
+
+/// Allow a single blank line between the paragraphs by default.
+fn default_max() -> usize {
+    1
+}
+
+impl NoConsecutiveBlankLines {
+    /// Find the first run of the blank lines longer than the maximum.
+    /// Returns the line number in the body (starting from 1) where the run starts and its length.
+    fn find_run(&self, message: &Message) -> Option<(usize, usize)> {
+        let body = message.body.as_deref()?;
+
+        let mut start = 0;
+        let mut run = 0;
+        for (i, line) in body.lines().enumerate() {
+            if !line.trim().is_empty() {
+                run = 0;
+                continue;
+            }
+
+            if run == 0 {
+                start = i + 1;
+            }
+            run += 1;
+
+            if run > self.max {
+                // Count the rest of the run to report its whole length.
+                let rest = body
+                    .lines()
+                    .skip(i + 1)
+                    .take_while(|line| line.trim().is_empty())
+                    .count();
+                return Some((start, run + rest));
+            }
+        }
+
+        None
+    }
+}
+
+/// NoConsecutiveBlankLines represents the no-consecutive-blank-lines rule.
+impl Rule for NoConsecutiveBlankLines {
+    const NAME: &'static str = "no-consecutive-blank-lines";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        match self.find_run(message) {
+            Some((line, run)) => format!(
+                "body has {} consecutive blank lines from line {}, more than {}",
+                run, line, self.max
+            ),
+            None => format!("body has more than {} consecutive blank lines", self.max),
+        }
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if self.find_run(message).is_some() {
+            return Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            });
+        }
+
+        None
+    }
+}
+
+/// Default implementation of NoConsecutiveBlankLines.
+impl Default for NoConsecutiveBlankLines {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            max: default_max(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_blank_lines() {
+        let rule = NoConsecutiveBlankLines::default();
+        let message = Message {
+            body: Some("Hello world\n\nHow are you?\n\nI'm fine.".to_string()),
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag
+
+Hello world
+
+How are you?
+
+I'm fine."
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_double_blank_lines() {
+        let rule = NoConsecutiveBlankLines::default();
+        let message = Message {
+            body: Some("Hello world\n\n\nHow are you?".to_string()),
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag
+
+Hello world
+
+
+How are you?"
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "body has 2 consecutive blank lines from line 2, more than 1".to_string()
+        );
+    }
+
+    #[test]
+    fn test_max() {
+        let rule = NoConsecutiveBlankLines {
+            max: 2,
+            ..Default::default()
+        };
+        let message = Message {
+            body: Some("Hello world\n\n\nHow are you?".to_string()),
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag
+
+Hello world
+
+
+How are you?"
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+}
@@ -0,0 +1,90 @@
+use std::collections::HashSet;
+
+use crate::{message::Message, result::Violation, rule::BatchRule};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// NoDuplicateSubject represents the no-duplicate-subject rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NoDuplicateSubject {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+}
+
+/// NoDuplicateSubject represents the no-duplicate-subject rule.
+impl BatchRule for NoDuplicateSubject {
+    const NAME: &'static str = "no-duplicate-subject";
+    const LEVEL: Level = Level::Error;
+
+    fn validate_batch(&self, messages: &[Message]) -> Vec<Violation> {
+        let mut seen: HashSet<&str> = HashSet::new();
+        let mut reported: HashSet<&str> = HashSet::new();
+        let mut violations = Vec::new();
+
+        for subject in messages
+            .iter()
+            .filter_map(|message| message.subject.as_deref())
+        {
+            // Empty subjects are governed by the subject-empty rule.
+            if subject.trim().is_empty() || seen.insert(subject) || !reported.insert(subject) {
+                continue;
+            }
+
+            violations.push(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: format!("subject {:?} is used by multiple commits", subject),
+                rule: Self::NAME.to_string(),
+            });
+        }
+
+        violations
+    }
+}
+
+/// Default implementation of NoDuplicateSubject.
+impl Default for NoDuplicateSubject {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unique_subjects() {
+        let rule = NoDuplicateSubject::default();
+        let messages = vec![
+            Message::new("feat(cli): add new flag".to_string()),
+            Message::new("fix(cli): handle empty flag".to_string()),
+        ];
+
+        assert!(rule.validate_batch(&messages).is_empty());
+    }
+
+    #[test]
+    fn test_duplicate_subjects() {
+        let rule = NoDuplicateSubject::default();
+        let messages = vec![
+            Message::new("feat(cli): add new flag".to_string()),
+            Message::new("fix: typo".to_string()),
+            Message::new("feat(cli): add new flag\n\nHello world".to_string()),
+            Message::new("feat(cli): add new flag".to_string()),
+        ];
+
+        let violations = rule.validate_batch(&messages);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].level, Level::Error);
+        assert_eq!(
+            violations[0].message,
+            "subject \"feat(cli): add new flag\" is used by multiple commits".to_string()
+        );
+    }
+}
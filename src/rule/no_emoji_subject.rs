@@ -0,0 +1,148 @@
+use crate::{emoji, message::Message, result::Violation, rule::Rule};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// NoEmojiSubject represents the no-emoji-subject rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NoEmojiSubject {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// RequireEmoji represents whether the subject must contain an emoji instead.
+    /// It is useful for the teams using [gitmoji](https://gitmoji.dev).
+    #[serde(default)]
+    require_emoji: bool,
+}
+
+/// NoEmojiSubject represents the no-emoji-subject rule.
+impl Rule for NoEmojiSubject {
+    const NAME: &'static str = "no-emoji-subject";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        if self.require_emoji {
+            return "subject must contain an emoji".to_string();
+        }
+
+        let found = emoji::find(message.subject.as_deref().unwrap_or_default());
+        format!("subject must not contain emoji but found {:?}", found)
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        let found = emoji::find(message.subject.as_deref().unwrap_or_default());
+
+        if found.is_empty() == self.require_emoji {
+            return Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            });
+        }
+
+        None
+    }
+}
+
+/// Default implementation of NoEmojiSubject.
+impl Default for NoEmojiSubject {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            require_emoji: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_emoji() {
+        let rule = NoEmojiSubject::default();
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag".to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_emoji() {
+        let rule = NoEmojiSubject::default();
+        let message = Message {
+            body: None,
+            description: Some("🎉 add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): 🎉 add new flag".to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): 🎉 add new flag".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "subject must not contain emoji but found [\"🎉\"]".to_string()
+        );
+    }
+
+    #[test]
+    fn test_require_emoji() {
+        let rule = NoEmojiSubject {
+            require_emoji: true,
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: Some("🎉 add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): 🎉 add new flag".to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): 🎉 add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_require_emoji_missing() {
+        let rule = NoEmojiSubject {
+            require_emoji: true,
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag".to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(
+            violation.unwrap().message,
+            "subject must contain an emoji".to_string()
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use crate::{message::Message, result::Violation, rule::Rule};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// NoFixup represents the no-fixup rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NoFixup {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Prefixes represents the forbidden prefixes of the subject.
+    #[serde(default = "default_prefixes")]
+    prefixes: Vec<String>,
+}
+
+/// Prefixes of the commits which should be squashed before merge.
+/// `fixup!`, `squash!` and `amend!` are added by `git commit --fixup` and `--squash`.
+fn default_prefixes() -> Vec<String> {
+    ["fixup!", "squash!", "amend!", "WIP"]
+        .iter()
+        .map(|p| p.to_string())
+        .collect()
+}
+
+impl NoFixup {
+    /// Find the forbidden prefix of the subject.
+    fn find_prefix(&self, message: &Message) -> Option<&String> {
+        let subject = message.subject.as_deref()?;
+        self.prefixes
+            .iter()
+            .find(|prefix| subject.starts_with(prefix.as_str()))
+    }
+}
+
+/// NoFixup represents the no-fixup rule.
+impl Rule for NoFixup {
+    const NAME: &'static str = "no-fixup";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        match self.find_prefix(message) {
+            Some(prefix) => format!(
+                "subject starts with {:?}. Squash the commit before merge",
+                prefix
+            ),
+            None => "subject has a forbidden prefix. Squash the commit before merge".to_string(),
+        }
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if self.find_prefix(message).is_some() {
+            return Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            });
+        }
+
+        None
+    }
+}
+
+/// Default implementation of NoFixup.
+impl Default for NoFixup {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            prefixes: default_prefixes(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normal_commit() {
+        let rule = NoFixup::default();
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag".to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_fixup_commit() {
+        let rule = NoFixup::default();
+        let message = Message {
+            body: None,
+            description: Some("fixup! feat(cli): add new flag".to_string()),
+            footers: None,
+            r#type: None,
+            raw: "fixup! feat(cli): add new flag".to_string(),
+            scope: None,
+            separator: ":".to_string(),
+            subject: Some("fixup! feat(cli): add new flag".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "subject starts with \"fixup!\". Squash the commit before merge".to_string()
+        );
+    }
+
+    #[test]
+    fn test_custom_prefixes() {
+        let rule = NoFixup {
+            prefixes: vec!["[WIP]".to_string()],
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: Some("[WIP] add new flag".to_string()),
+            footers: None,
+            r#type: None,
+            raw: "[WIP] add new flag".to_string(),
+            scope: None,
+            separator: ":".to_string(),
+            subject: Some("[WIP] add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_some());
+    }
+}
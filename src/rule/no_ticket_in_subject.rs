@@ -0,0 +1,107 @@
+use crate::{message::Message, pattern, result::Violation, rule::Rule};
+use serde::{Deserialize, Serialize};
+
+use super::{ticket_consistency::default_pattern, Level};
+
+/// NoTicketInSubject represents the no-ticket-in-subject rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NoTicketInSubject {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Pattern represents the regex matching the ticket IDs.
+    #[serde(default = "default_pattern")]
+    pattern: String,
+}
+
+impl NoTicketInSubject {
+    /// Find the first ticket ID in the subject.
+    fn find_ticket<'a>(&self, message: &'a Message) -> Result<Option<&'a str>, regex::Error> {
+        let regex = pattern::compile(&self.pattern)?;
+
+        Ok(message
+            .subject
+            .as_deref()
+            .and_then(|subject| regex.find(subject))
+            .map(|ticket| ticket.as_str()))
+    }
+}
+
+/// NoTicketInSubject represents the no-ticket-in-subject rule.
+impl Rule for NoTicketInSubject {
+    const NAME: &'static str = "no-ticket-in-subject";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        match self.find_ticket(message) {
+            Ok(ticket) => format!(
+                "subject must not contain the ticket ID {} but reference it in the footers",
+                ticket.unwrap_or_default()
+            ),
+            Err(err) => err.to_string(),
+        }
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        match self.find_ticket(message) {
+            Ok(None) => None,
+            _ => Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            }),
+        }
+    }
+}
+
+/// Default implementation of NoTicketInSubject.
+impl Default for NoTicketInSubject {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            pattern: default_pattern(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ticket_in_footer() {
+        let rule = NoTicketInSubject::default();
+        let message = Message::new("feat(cli): add new flag\n\nRefs: #ABC-1".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_ticket_in_subject() {
+        let rule = NoTicketInSubject::default();
+        let message = Message::new("feat(cli): #ABC-1 add new flag".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "subject must not contain the ticket ID #ABC-1 but reference it in the footers"
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_invalid_pattern() {
+        let rule = NoTicketInSubject {
+            pattern: "(".to_string(),
+            ..Default::default()
+        };
+        let message = Message::new("feat(cli): add new flag".to_string());
+
+        assert!(rule.validate(&message).is_some());
+    }
+}
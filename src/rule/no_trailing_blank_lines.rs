@@ -0,0 +1,151 @@
+use crate::{message::Message, result::Violation, rule::Rule};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// NoTrailingBlankLines represents the no-trailing-blank-lines rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NoTrailingBlankLines {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+}
+
+/// Count the blank lines at the end of the raw commit message.
+/// Note that the single newline terminating the last line is not a blank line.
+fn count_trailing_blank_lines(raw: &str) -> usize {
+    raw.strip_suffix('\n')
+        .unwrap_or(raw)
+        .split('\n')
+        .rev()
+        .take_while(|line| line.trim().is_empty())
+        .count()
+}
+
+/// NoTrailingBlankLines represents the no-trailing-blank-lines rule.
+impl Rule for NoTrailingBlankLines {
+    const NAME: &'static str = "no-trailing-blank-lines";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        format!(
+            "message must not end with blank lines (found {})",
+            count_trailing_blank_lines(&message.raw)
+        )
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        // A blank message is governed by the subject-empty rule.
+        if message.raw.trim().is_empty() {
+            return None;
+        }
+
+        if count_trailing_blank_lines(&message.raw) > 0 {
+            return Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            });
+        }
+
+        None
+    }
+
+    fn fix(&self, message: &mut Message) {
+        if message.raw.trim().is_empty() {
+            return;
+        }
+
+        let mut lines: Vec<&str> = message.raw.lines().collect();
+        while lines.last().is_some_and(|line| line.trim().is_empty()) {
+            lines.pop();
+        }
+
+        let mut raw = lines.join("\n");
+        if message.raw.ends_with('\n') {
+            raw.push('\n');
+        }
+
+        *message = message.with_raw(raw);
+    }
+}
+
+/// Default implementation of NoTrailingBlankLines.
+impl Default for NoTrailingBlankLines {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_without_trailing_blank_lines() {
+        let rule = NoTrailingBlankLines::default();
+
+        let message = Message::new("feat(cli): add new flag\n".to_string());
+        assert!(rule.validate(&message).is_none());
+
+        let message = Message::new("feat(cli): add new flag\n\nHello, I'm the body.".to_string());
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_with_trailing_blank_lines() {
+        let rule = NoTrailingBlankLines::default();
+
+        let message = Message::new("feat(cli): add new flag\n\n\n".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "message must not end with blank lines (found 2)".to_string()
+        );
+    }
+
+    #[test]
+    fn test_whitespace_only_lines() {
+        let rule = NoTrailingBlankLines::default();
+
+        let message = Message::new("feat(cli): add new flag\n\nHello\n  \n".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(
+            violation.unwrap().message,
+            "message must not end with blank lines (found 1)".to_string()
+        );
+    }
+
+    #[test]
+    fn test_fix() {
+        let rule = NoTrailingBlankLines::default();
+        let mut message = Message::new("feat(cli): add new flag\n\nHello\n\n \n".to_string());
+
+        rule.fix(&mut message);
+        assert_eq!(
+            message.raw,
+            "feat(cli): add new flag\n\nHello\n".to_string()
+        );
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_fix_with_separator() {
+        let rule = NoTrailingBlankLines::default();
+        let mut message = Message::with_separator("feat(cli)/ add new flag\n\n".to_string(), "/");
+
+        rule.fix(&mut message);
+        assert_eq!(message.raw, "feat(cli)/ add new flag\n".to_string());
+        assert_eq!(message.r#type, Some("feat".to_string()));
+        assert_eq!(message.separator, "/".to_string());
+    }
+}
@@ -0,0 +1,191 @@
+use crate::{message::Message, result::Violation, rule::Rule};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// NoTrailingWhitespace represents the no-trailing-whitespace rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NoTrailingWhitespace {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+}
+
+/// Find the line numbers (starting from 1) of the lines with trailing spaces or tabs.
+fn find_lines(raw: &str) -> Vec<usize> {
+    raw.lines()
+        .enumerate()
+        .filter(|(_, line)| line.ends_with([' ', '\t']))
+        .map(|(i, _)| i + 1)
+        .collect()
+}
+
+/// NoTrailingWhitespace represents the no-trailing-whitespace rule.
+impl Rule for NoTrailingWhitespace {
+    const NAME: &'static str = "no-trailing-whitespace";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        let lines: Vec<String> = find_lines(&message.raw)
+            .iter()
+            .map(|line| line.to_string())
+            .collect();
+
+        match lines.len() {
+            0 => "message has trailing whitespace".to_string(),
+            1 => format!("message has trailing whitespace on line {}", lines[0]),
+            _ => format!(
+                "message has trailing whitespace on lines {}",
+                lines.join(", ")
+            ),
+        }
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if !find_lines(&message.raw).is_empty() {
+            return Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            });
+        }
+
+        None
+    }
+
+    fn fix(&self, message: &mut Message) {
+        // Only the spaces and the tabs are trimmed so that the line endings are kept as they are.
+        let raw: String = message
+            .raw
+            .split_inclusive('\n')
+            .map(|line| {
+                let content = line.trim_end_matches(['\r', '\n']);
+                let ending = &line[content.len()..];
+                format!("{}{}", content.trim_end_matches([' ', '\t']), ending)
+            })
+            .collect();
+
+        *message = message.with_raw(raw);
+    }
+}
+
+/// Default implementation of NoTrailingWhitespace.
+impl Default for NoTrailingWhitespace {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_without_trailing_whitespace() {
+        let rule = NoTrailingWhitespace::default();
+        let message = Message {
+            body: Some("Hello, I'm the body.".to_string()),
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag
+
+Hello, I'm the body."
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_with_trailing_whitespace() {
+        let rule = NoTrailingWhitespace::default();
+        let message = Message {
+            body: Some("Hello, I'm the body.".to_string()),
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag
+
+Hello, I'm the body.  "
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "message has trailing whitespace on line 3".to_string()
+        );
+    }
+
+    #[test]
+    fn test_trailing_whitespace_on_multiple_lines() {
+        let rule = NoTrailingWhitespace::default();
+        let message = Message::new(
+            "feat(cli): add new flag\t\n\nHello, I'm the body.\nAnd the second line. ".to_string(),
+        );
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(
+            violation.unwrap().message,
+            "message has trailing whitespace on lines 1, 4".to_string()
+        );
+    }
+
+    #[test]
+    fn test_fix() {
+        let rule = NoTrailingWhitespace::default();
+        let mut message = Message::new(
+            "feat(cli): add new flag \n\nHello, I'm the body.  \nAnd the second line.\t"
+                .to_string(),
+        );
+
+        rule.fix(&mut message);
+        assert_eq!(
+            message.raw,
+            "feat(cli): add new flag\n\nHello, I'm the body.\nAnd the second line.".to_string()
+        );
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_fix_keeps_line_endings() {
+        let rule = NoTrailingWhitespace::default();
+        let mut message =
+            Message::new("feat(cli): add new flag \r\n\r\nHello, I'm the body.\t\n".to_string());
+
+        rule.fix(&mut message);
+        assert_eq!(
+            message.raw,
+            "feat(cli): add new flag\r\n\r\nHello, I'm the body.\n".to_string()
+        );
+
+        let fixed = message.raw.clone();
+        rule.fix(&mut message);
+        assert_eq!(message.raw, fixed);
+    }
+
+    #[test]
+    fn test_fix_with_separator() {
+        let rule = NoTrailingWhitespace::default();
+        let mut message = Message::with_separator("feat(cli)/ add new flag \n".to_string(), "/");
+
+        rule.fix(&mut message);
+        assert_eq!(message.raw, "feat(cli)/ add new flag\n".to_string());
+        assert_eq!(message.r#type, Some("feat".to_string()));
+        assert_eq!(message.description, Some("add new flag".to_string()));
+    }
+}
@@ -0,0 +1,147 @@
+use crate::{message::Message, result::Violation, rule::Rule};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// Suffixes of the words derived from the type such as `fixes` and `featured`.
+const SUFFIXES: [&str; 7] = ["s", "es", "d", "ed", "ing", "ure", "ures"];
+
+/// NoTypeInSubject represents the no-type-in-subject rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NoTypeInSubject {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+}
+
+/// Check whether the word is the type or derived from it.
+/// The plural type such as `docs` also matches the singular word `doc`.
+fn stems_from(word: &str, r#type: &str) -> bool {
+    if word == r#type || r#type.strip_suffix('s') == Some(word) {
+        return true;
+    }
+
+    word.strip_prefix(r#type)
+        .is_some_and(|suffix| SUFFIXES.contains(&suffix))
+}
+
+impl NoTypeInSubject {
+    /// Find the first word of the description repeating the type.
+    fn find_repeat<'a>(&self, message: &'a Message) -> Option<&'a str> {
+        let r#type = message.r#type.as_deref()?.to_lowercase();
+        let word = message.description.as_deref()?.split_whitespace().next()?;
+
+        let normalized = word
+            .trim_matches(|c: char| !c.is_alphanumeric())
+            .to_lowercase();
+        if !r#type.is_empty() && stems_from(&normalized, &r#type) {
+            return Some(word);
+        }
+
+        None
+    }
+}
+
+/// NoTypeInSubject represents the no-type-in-subject rule.
+impl Rule for NoTypeInSubject {
+    const NAME: &'static str = "no-type-in-subject";
+    const LEVEL: Level = Level::Warning;
+
+    fn message(&self, message: &Message) -> String {
+        format!(
+            "description should not repeat the type {} but starts with {:?}",
+            message.r#type.as_deref().unwrap_or_default(),
+            self.find_repeat(message).unwrap_or_default()
+        )
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if self.find_repeat(message).is_some() {
+            return Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            });
+        }
+
+        None
+    }
+}
+
+/// Default implementation of NoTypeInSubject.
+impl Default for NoTypeInSubject {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repeated_type() {
+        let rule = NoTypeInSubject::default();
+        let message = Message {
+            body: None,
+            description: Some("fix login".to_string()),
+            footers: None,
+            r#type: Some("fix".to_string()),
+            raw: "fix: fix login".to_string(),
+            scope: None,
+            separator: ":".to_string(),
+            subject: Some("fix: fix login".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Warning);
+        assert_eq!(
+            violation.unwrap().message,
+            "description should not repeat the type fix but starts with \"fix\"".to_string()
+        );
+    }
+
+    #[test]
+    fn test_derived_word() {
+        let rule = NoTypeInSubject::default();
+
+        for raw in [
+            "fix: Fixed login",
+            "feat: feature flags",
+            "docs: doc comments",
+        ] {
+            let message = Message::new(raw.to_string());
+            assert!(rule.validate(&message).is_some(), "{}", raw);
+        }
+    }
+
+    #[test]
+    fn test_different_word() {
+        let rule = NoTypeInSubject::default();
+        let message = Message {
+            body: None,
+            description: Some("resolve login issue".to_string()),
+            footers: None,
+            r#type: Some("fix".to_string()),
+            raw: "fix: resolve login issue".to_string(),
+            scope: None,
+            separator: ":".to_string(),
+            subject: Some("fix: resolve login issue".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_word_starting_with_type() {
+        let rule = NoTypeInSubject::default();
+        let message = Message::new("fix: fixture for the parser".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
+}
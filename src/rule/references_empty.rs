@@ -0,0 +1,236 @@
+use crate::{message::Message, pattern, result::Violation, rule::Rule};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// ReferencesEmpty represents the references-empty rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ReferencesEmpty {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Forbidden represents whether the references must be empty.
+    /// If it is false, at least one reference is required instead.
+    #[serde(default)]
+    forbidden: bool,
+
+    /// Pattern represents the regex of an issue reference.
+    #[serde(default = "default_pattern")]
+    pattern: String,
+
+    /// Subject represents whether the references in the subject are counted.
+    #[serde(default)]
+    subject: bool,
+}
+
+/// Default regex matching references such as `#123` and `JIRA-4`.
+fn default_pattern() -> String {
+    r"(?:#\d+\b|\b[A-Z][A-Z0-9]+-\d+\b)".to_string()
+}
+
+impl ReferencesEmpty {
+    /// Get the parts of the message which are scanned for references.
+    fn scanned(&self, message: &Message) -> Vec<String> {
+        let mut texts = Vec::new();
+
+        if self.subject {
+            if let Some(subject) = &message.subject {
+                texts.push(subject.clone());
+            }
+        }
+
+        if let Some(body) = &message.body {
+            texts.push(body.clone());
+        }
+
+        if let Some(footers) = &message.footers {
+            texts.extend(footers.iter().map(|footer| footer.to_string()));
+        }
+
+        texts
+    }
+}
+
+/// ReferencesEmpty represents the references-empty rule.
+impl Rule for ReferencesEmpty {
+    const NAME: &'static str = "references-empty";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, _message: &Message) -> String {
+        if self.forbidden {
+            return "references are not empty".to_string();
+        }
+
+        "references are empty".to_string()
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        let regex = match pattern::compile(&self.pattern) {
+            Ok(regex) => regex,
+            Err(err) => {
+                return Some(Violation {
+                    level: self.level.unwrap_or(Self::LEVEL),
+                    message: err.to_string(),
+                    rule: Self::NAME.to_string(),
+                });
+            }
+        };
+
+        let is_empty = !self
+            .scanned(message)
+            .into_iter()
+            .any(|text| regex.is_match(&text));
+
+        if is_empty != self.forbidden {
+            return Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            });
+        }
+
+        None
+    }
+}
+
+/// Default implementation of ReferencesEmpty.
+impl Default for ReferencesEmpty {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            forbidden: false,
+            pattern: default_pattern(),
+            subject: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message::Footer;
+
+    #[test]
+    fn test_footer_reference() {
+        let rule = ReferencesEmpty::default();
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: Some(vec![Footer {
+                token: "Closes".to_string(),
+                value: "123".to_string(),
+                separator: '#',
+            }]),
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag
+
+Closes #123"
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_body_reference() {
+        let rule = ReferencesEmpty::default();
+        let message = Message {
+            body: Some("Refs JIRA-4".to_string()),
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag
+
+Refs JIRA-4"
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_no_reference() {
+        let rule = ReferencesEmpty::default();
+        let message = Message {
+            body: Some("Hello world".to_string()),
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag
+
+Hello world"
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "references are empty".to_string()
+        );
+    }
+
+    #[test]
+    fn test_subject_reference() {
+        let message = Message {
+            body: None,
+            description: Some("add new flag (#123)".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag (#123)".to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag (#123)".to_string()),
+        };
+
+        let rule = ReferencesEmpty::default();
+        assert!(rule.validate(&message).is_some());
+
+        let rule = ReferencesEmpty {
+            subject: true,
+            ..Default::default()
+        };
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_forbidden() {
+        let rule = ReferencesEmpty {
+            forbidden: true,
+            ..Default::default()
+        };
+        let message = Message {
+            body: Some("Refs JIRA-4".to_string()),
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag
+
+Refs JIRA-4"
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(
+            violation.unwrap().message,
+            "references are not empty".to_string()
+        );
+    }
+}
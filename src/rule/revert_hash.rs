@@ -0,0 +1,132 @@
+use crate::{message::Message, pattern, result::Violation, rule::Rule};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// RevertHash represents the revert-hash rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RevertHash {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+}
+
+impl RevertHash {
+    /// Find the reverted commit of the `This reverts commit <sha>.` line added by `git revert`.
+    /// Returns `None` if there is no such line.
+    fn find_reverted<'a>(&self, message: &'a Message) -> Option<&'a str> {
+        let re = pattern::compile(r"(?m)^This reverts commit (?P<sha>\S*?)\.?\s*$").unwrap();
+
+        re.captures(&message.raw)
+            .and_then(|captures| captures.name("sha"))
+            .map(|sha| sha.as_str())
+    }
+}
+
+/// Check whether the text is an abbreviated or a full commit SHA.
+fn is_sha(text: &str) -> bool {
+    (7..=40).contains(&text.len()) && text.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// RevertHash represents the revert-hash rule.
+impl Rule for RevertHash {
+    const NAME: &'static str = "revert-hash";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        match self.find_reverted(message) {
+            Some(sha) => format!(
+                "reverted commit {:?} must be a SHA of 7 to 40 hexadecimal characters",
+                sha
+            ),
+            None => "revert must reference the reverted commit with \"This reverts commit <sha>.\""
+                .to_string(),
+        }
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        let is_revert = message.is_revert() || message.r#type.as_deref() == Some("revert");
+        if !is_revert {
+            return None;
+        }
+
+        match self.find_reverted(message) {
+            Some(sha) if is_sha(sha) => None,
+            _ => Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            }),
+        }
+    }
+}
+
+/// Default implementation of RevertHash.
+impl Default for RevertHash {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_revert_with_sha() {
+        let rule = RevertHash::default();
+        let message = Message::new(
+            "Revert \"feat(cli): add new flag\"
+
+This reverts commit 5f2c3d1a9b7e6f4c8d0a1b2c3d4e5f6a7b8c9d0e."
+                .to_string(),
+        );
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_revert_without_sha() {
+        let rule = RevertHash::default();
+        let message = Message::new("revert: add new flag".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "revert must reference the reverted commit with \"This reverts commit <sha>.\""
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_revert_with_short_sha() {
+        let rule = RevertHash::default();
+        let message = Message::new(
+            "Revert \"feat(cli): add new flag\"
+
+This reverts commit 5f2c."
+                .to_string(),
+        );
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(
+            violation.unwrap().message,
+            "reverted commit \"5f2c\" must be a SHA of 7 to 40 hexadecimal characters".to_string()
+        );
+    }
+
+    #[test]
+    fn test_not_revert() {
+        let rule = RevertHash::default();
+        let message = Message::new("feat(cli): add new flag".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
+}
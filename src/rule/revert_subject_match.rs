@@ -0,0 +1,117 @@
+use crate::{message::Message, result::Violation, rule::Rule};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// RevertSubjectMatch represents the revert-subject-match rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RevertSubjectMatch {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+}
+
+/// Get the quoted subject of the reverted commit such as `feat: add new flag`
+/// of `Revert "feat: add new flag"`.
+/// Returns `None` if the closing quote is missing.
+fn reverted_subject(subject: &str) -> Option<&str> {
+    subject.strip_prefix("Revert \"")?.strip_suffix('"')
+}
+
+/// Check whether the reverted subject is kept as it is written by `git revert`.
+fn is_intact(subject: &str) -> bool {
+    reverted_subject(subject)
+        .is_some_and(|reverted| !reverted.trim().is_empty() && reverted.trim() == reverted)
+}
+
+/// RevertSubjectMatch represents the revert-subject-match rule.
+impl Rule for RevertSubjectMatch {
+    const NAME: &'static str = "revert-subject-match";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        format!(
+            "revert must quote the subject of the reverted commit as Revert \"<subject>\" (found {:?})",
+            message.subject.as_deref().unwrap_or_default()
+        )
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if !message.is_revert() {
+            return None;
+        }
+
+        match &message.subject {
+            Some(subject) if is_intact(subject) => None,
+            _ => Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            }),
+        }
+    }
+}
+
+/// Default implementation of RevertSubjectMatch.
+impl Default for RevertSubjectMatch {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_revert_with_subject() {
+        let rule = RevertSubjectMatch::default();
+
+        assert!(rule
+            .validate(&Message::new(
+                "Revert \"feat(cli): add new flag\"\n\nThis reverts commit 5f2c3d1.".to_string()
+            ))
+            .is_none());
+        assert!(rule
+            .validate(&Message::new(
+                "Revert \"Revert \"feat(cli): add new flag\"\"".to_string()
+            ))
+            .is_none());
+        assert!(rule
+            .validate(&Message::new("feat(cli): add new flag".to_string()))
+            .is_none());
+    }
+
+    #[test]
+    fn test_revert_with_empty_quote() {
+        let rule = RevertSubjectMatch::default();
+        let message = Message::new("Revert \"\"\n\nThis reverts commit 5f2c3d1.".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "revert must quote the subject of the reverted commit as Revert \"<subject>\" (found \"Revert \\\"\\\"\")"
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_revert_with_stripped_subject() {
+        let rule = RevertSubjectMatch::default();
+
+        assert!(rule
+            .validate(&Message::new("Revert \"feat(cli): add".to_string()))
+            .is_some());
+        assert!(rule
+            .validate(&Message::new(
+                "Revert \" feat(cli): add new flag\"".to_string()
+            ))
+            .is_some());
+    }
+}
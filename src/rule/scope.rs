@@ -36,15 +36,11 @@ impl Rule for Scope {
 
     fn validate(&self, message: &Message) -> Option<Violation> {
         match &message.scope {
-            None => {
-                if self.options.is_empty() {
-                    return None;
-                }
+            None if self.options.is_empty() => {
+                return None;
             }
-            Some(scope) if scope.is_empty() => {
-                if self.options.is_empty() {
-                    return None;
-                }
+            Some(scope) if scope.is_empty() && self.options.is_empty() => {
+                return None;
             }
             Some(scope) if self.options.contains(scope) => {
                 return None;
@@ -55,6 +51,7 @@ impl Rule for Scope {
         Some(Violation {
             level: self.level.unwrap_or(Self::LEVEL),
             message: self.message(message),
+            rule: Self::NAME.to_string(),
         })
     }
 }
@@ -87,6 +84,7 @@ mod tests {
                 r#type: None,
                 raw: "".to_string(),
                 scope: Some("".to_string()),
+                separator: ":".to_string(),
                 subject: None,
             };
 
@@ -105,6 +103,7 @@ mod tests {
                 r#type: None,
                 raw: "".to_string(),
                 scope: None,
+                separator: ":".to_string(),
                 subject: None,
             };
 
@@ -123,6 +122,7 @@ mod tests {
                 r#type: Some("feat".to_string()),
                 raw: "feat(web): broadcast $destroy event on scope destruction".to_string(),
                 scope: Some("web".to_string()),
+                separator: ":".to_string(),
                 subject: None,
             };
 
@@ -140,8 +140,10 @@ mod tests {
         use super::*;
         #[test]
         fn test_empty_scope() {
-            let mut rule = Scope::default();
-            rule.options = vec!["api".to_string(), "web".to_string()];
+            let rule = Scope {
+                options: vec!["api".to_string(), "web".to_string()],
+                ..Default::default()
+            };
 
             let message = Message {
                 body: None,
@@ -150,6 +152,7 @@ mod tests {
                 r#type: None,
                 raw: "".to_string(),
                 scope: Some("".to_string()),
+                separator: ":".to_string(),
                 subject: None,
             };
 
@@ -164,8 +167,10 @@ mod tests {
 
         #[test]
         fn test_none_scope() {
-            let mut rule = Scope::default();
-            rule.options = vec!["api".to_string(), "web".to_string()];
+            let rule = Scope {
+                options: vec!["api".to_string(), "web".to_string()],
+                ..Default::default()
+            };
 
             let message = Message {
                 body: None,
@@ -174,6 +179,7 @@ mod tests {
                 r#type: None,
                 raw: "".to_string(),
                 scope: None,
+                separator: ":".to_string(),
                 subject: None,
             };
 
@@ -188,8 +194,10 @@ mod tests {
 
         #[test]
         fn test_valid_scope() {
-            let mut rule = Scope::default();
-            rule.options = vec!["api".to_string(), "web".to_string()];
+            let rule = Scope {
+                options: vec!["api".to_string(), "web".to_string()],
+                ..Default::default()
+            };
 
             let message = Message {
                 body: None,
@@ -198,6 +206,7 @@ mod tests {
                 r#type: Some("feat".to_string()),
                 raw: "feat(web): broadcast $destroy event on scope destruction".to_string(),
                 scope: Some("web".to_string()),
+                separator: ":".to_string(),
                 subject: None,
             };
 
@@ -206,8 +215,10 @@ mod tests {
 
         #[test]
         fn test_invalid_scope() {
-            let mut rule = Scope::default();
-            rule.options = vec!["api".to_string(), "web".to_string()];
+            let rule = Scope {
+                options: vec!["api".to_string(), "web".to_string()],
+                ..Default::default()
+            };
 
             let message = Message {
                 body: None,
@@ -216,6 +227,7 @@ mod tests {
                 r#type: Some("feat".to_string()),
                 raw: "feat(invalid): broadcast $destroy event on scope destruction".to_string(),
                 scope: Some("invalid".to_string()),
+                separator: ":".to_string(),
                 subject: None,
             };
 
@@ -0,0 +1,234 @@
+use crate::{
+    case::{self, Case},
+    message::Message,
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// ScopeCase represents the scope-case rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ScopeCase {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Allowed represents the allowed cases of the scope.
+    /// Available cases are the same as the subject-case rule.
+    #[serde(deserialize_with = "case::deserialize_names")]
+    allowed: Vec<String>,
+
+    /// Delimiter represents the delimiter of the multiple scopes such as `,` for `feat(api,ui):`.
+    /// Each scope is checked separately if it is set.
+    delimiter: Option<String>,
+}
+
+impl ScopeCase {
+    /// Check whether the scope is written in one of the allowed cases.
+    fn matches(&self, scope: &str) -> bool {
+        self.allowed
+            .iter()
+            .filter_map(|name| Case::from_name(name))
+            .any(|case| case.matches(scope))
+    }
+}
+
+/// ScopeCase represents the scope-case rule.
+impl Rule for ScopeCase {
+    const NAME: &'static str = "scope-case";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        let found = message
+            .scopes(self.delimiter.as_deref())
+            .into_iter()
+            .find(|scope| !self.matches(scope))
+            .and_then(case::detect)
+            .map(|case| case.name())
+            .unwrap_or("mixed-case");
+
+        format!(
+            "scope must be in one of {:?} but found {}",
+            self.allowed, found
+        )
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        let scopes = message.scopes(self.delimiter.as_deref());
+
+        if !scopes.iter().all(|scope| self.matches(scope)) {
+            return Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            });
+        }
+
+        None
+    }
+}
+
+/// Default implementation of ScopeCase.
+impl Default for ScopeCase {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            allowed: vec!["lower-case".to_string()],
+            delimiter: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lower_case() {
+        let rule = ScopeCase::default();
+
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag".to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_kebab_case() {
+        let rule = ScopeCase {
+            allowed: vec!["kebab-case".to_string()],
+            ..Default::default()
+        };
+
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(my-feature): add new flag".to_string(),
+            scope: Some("my-feature".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(my-feature): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_snake_case() {
+        let rule = ScopeCase {
+            allowed: vec!["snake-case".to_string()],
+            ..Default::default()
+        };
+
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(my_feature): add new flag".to_string(),
+            scope: Some("my_feature".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(my_feature): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_camel_case() {
+        let rule = ScopeCase {
+            allowed: vec!["camel-case".to_string()],
+            ..Default::default()
+        };
+
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(myFeature): add new flag".to_string(),
+            scope: Some("myFeature".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(myFeature): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_no_scope() {
+        let rule = ScopeCase::default();
+
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat: add new flag".to_string(),
+            scope: None,
+            separator: ":".to_string(),
+            subject: Some("feat: add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_unexpected_case() {
+        let rule = ScopeCase {
+            allowed: vec!["kebab-case".to_string()],
+            ..Default::default()
+        };
+
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(myFeature): add new flag".to_string(),
+            scope: Some("myFeature".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(myFeature): add new flag".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "scope must be in one of [\"kebab-case\"] but found camel-case".to_string()
+        );
+    }
+
+    #[test]
+    fn test_multiple_scopes() {
+        let rule = ScopeCase {
+            delimiter: Some(",".to_string()),
+            ..Default::default()
+        };
+
+        let message = Message::new("feat(api,ui): x".to_string());
+        assert!(rule.validate(&message).is_none());
+
+        let message = Message::new("feat(api,UI): x".to_string());
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(
+            violation.unwrap().message,
+            "scope must be in one of [\"lower-case\"] but found upper-case".to_string()
+        );
+    }
+}
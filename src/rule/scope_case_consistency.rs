@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+
+use crate::{message::Message, result::Violation, rule::BatchRule};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// ScopeCaseConsistency represents the scope-case-consistency rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ScopeCaseConsistency {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+}
+
+/// ScopeCaseConsistency represents the scope-case-consistency rule.
+impl BatchRule for ScopeCaseConsistency {
+    const NAME: &'static str = "scope-case-consistency";
+    const LEVEL: Level = Level::Error;
+
+    fn validate_batch(&self, messages: &[Message]) -> Vec<Violation> {
+        // The first spelling of each scope is compared with the later ones.
+        let mut spellings: HashMap<String, &str> = HashMap::new();
+        let mut reported: Vec<&str> = Vec::new();
+        let mut violations = Vec::new();
+
+        for scope in messages
+            .iter()
+            .filter_map(|message| message.scope.as_deref())
+        {
+            if scope.is_empty() {
+                continue;
+            }
+
+            let first = *spellings.entry(scope.to_lowercase()).or_insert(scope);
+            if first == scope || reported.contains(&scope) {
+                continue;
+            }
+
+            reported.push(scope);
+            violations.push(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: format!("scope {} differs only in case from {}", scope, first),
+                rule: Self::NAME.to_string(),
+            });
+        }
+
+        violations
+    }
+}
+
+/// Default implementation of ScopeCaseConsistency.
+impl Default for ScopeCaseConsistency {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_consistent_scopes() {
+        let rule = ScopeCaseConsistency::default();
+        let messages = vec![
+            Message::new("feat(api): add new endpoint".to_string()),
+            Message::new("fix(api): handle empty body".to_string()),
+            Message::new("feat(ui): add new button".to_string()),
+            Message::new("docs: fix typo".to_string()),
+        ];
+
+        assert!(rule.validate_batch(&messages).is_empty());
+    }
+
+    #[test]
+    fn test_inconsistent_scopes() {
+        let rule = ScopeCaseConsistency::default();
+        let messages = vec![
+            Message::new("feat(api): add new endpoint".to_string()),
+            Message::new("feat(API): add another endpoint".to_string()),
+            Message::new("fix(API): handle empty body".to_string()),
+        ];
+
+        let violations = rule.validate_batch(&messages);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].level, Level::Error);
+        assert_eq!(
+            violations[0].message,
+            "scope API differs only in case from api".to_string()
+        );
+    }
+}
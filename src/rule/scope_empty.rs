@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 
 use super::Level;
 
-/// ScopeEmpty represents the subject-empty rule.
+/// ScopeEmpty represents the scope-empty rule.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ScopeEmpty {
     /// Level represents the level of the rule.
@@ -11,6 +11,11 @@ pub struct ScopeEmpty {
     // Note that currently the default literal is not supported.
     // See: https://github.com/serde-rs/serde/issues/368
     level: Option<Level>,
+
+    /// Forbidden represents whether the scope must be empty.
+    /// If it is false, the scope must not be empty instead.
+    #[serde(default)]
+    forbidden: bool,
 }
 
 /// ScopeEmpty represents the scope-empty rule.
@@ -19,14 +24,25 @@ impl Rule for ScopeEmpty {
     const LEVEL: Level = Level::Error;
 
     fn message(&self, _message: &Message) -> String {
+        if self.forbidden {
+            return "scope is not empty".to_string();
+        }
+
         "scope is empty".to_string()
     }
 
     fn validate(&self, message: &Message) -> Option<Violation> {
-        if message.scope.is_none() {
+        // Note that the empty parentheses such as `feat(): ` are empty.
+        let is_empty = match &message.scope {
+            None => true,
+            Some(scope) => scope.trim().is_empty(),
+        };
+
+        if is_empty != self.forbidden {
             return Some(Violation {
                 level: self.level.unwrap_or(Self::LEVEL),
                 message: self.message(message),
+                rule: Self::NAME.to_string(),
             });
         }
 
@@ -39,6 +55,7 @@ impl Default for ScopeEmpty {
     fn default() -> Self {
         Self {
             level: Some(Self::LEVEL),
+            forbidden: false,
         }
     }
 }
@@ -57,6 +74,7 @@ mod tests {
             r#type: Some("feat".to_string()),
             raw: "feat(scope): broadcast $destroy event on scope destruction".to_string(),
             scope: Some("scope".to_string()),
+            separator: ":".to_string(),
             subject: None,
         };
 
@@ -73,16 +91,14 @@ mod tests {
             r#type: Some("feat".to_string()),
             raw: "feat: broadcast $destroy event on scope destruction".to_string(),
             scope: None,
+            separator: ":".to_string(),
             subject: None,
         };
 
         let violation = rule.validate(&message);
         assert!(violation.is_some());
         assert_eq!(violation.clone().unwrap().level, Level::Error);
-        assert_eq!(
-            violation.unwrap().message,
-            "scope is empty".to_string()
-        );
+        assert_eq!(violation.unwrap().message, "scope is empty".to_string());
     }
 
     #[test]
@@ -94,16 +110,36 @@ mod tests {
             footers: None,
             r#type: Some("feat".to_string()),
             raw: "feat(): broadcast $destroy event on scope destruction".to_string(),
-            scope: None,
+            scope: Some("".to_string()),
+            separator: ":".to_string(),
             subject: None,
         };
 
         let violation = rule.validate(&message);
         assert!(violation.is_some());
         assert_eq!(violation.clone().unwrap().level, Level::Error);
-        assert_eq!(
-            violation.unwrap().message,
-            "scope is empty".to_string()
-        );
+        assert_eq!(violation.unwrap().message, "scope is empty".to_string());
+    }
+
+    #[test]
+    fn test_forbidden_scope() {
+        let rule = ScopeEmpty {
+            forbidden: true,
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: None,
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(scope): broadcast $destroy event on scope destruction".to_string(),
+            scope: Some("scope".to_string()),
+            separator: ":".to_string(),
+            subject: None,
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.unwrap().message, "scope is not empty".to_string());
     }
 }
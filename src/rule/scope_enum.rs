@@ -0,0 +1,406 @@
+use crate::{message::Message, result::Violation, rule::Rule};
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+use super::Level;
+
+/// ScopeEnum represents the scope-enum rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ScopeEnum {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Allowed represents the allowed scopes.
+    /// The glob patterns with `*` and `?` such as `pkg-*` are also supported.
+    #[serde(default)]
+    allowed: Vec<String>,
+
+    /// CaseInsensitive represents whether the scopes are compared case-insensitively.
+    #[serde(default)]
+    case_insensitive: bool,
+
+    /// Directories represents the directories whose subdirectory names are allowed,
+    /// e.g. `packages` for the monorepo with the packages in `packages/*`.
+    #[serde(default)]
+    directories: Vec<String>,
+
+    /// File represents the path to the file listing the allowed scopes, one per line.
+    /// Blank lines and lines starting with `#` are skipped.
+    file: Option<String>,
+
+    /// Delimiter represents the delimiter of the multiple scopes such as `,` for `feat(api,ui):`.
+    /// Each scope is checked separately if it is set.
+    delimiter: Option<String>,
+}
+
+impl ScopeEnum {
+    /// Get the allowed scopes.
+    ///
+    /// Note that the file and the directories are read every time the scopes are
+    /// checked, so that the changes of the packages are followed without editing the config.
+    fn allowed_scopes(&self) -> Result<Vec<String>, String> {
+        let mut scopes = self.allowed.clone();
+
+        if let Some(path) = &self.file {
+            let content = fs::read_to_string(path)
+                .map_err(|err| format!("failed to read scopes from {}: {}", path, err))?;
+            scopes.extend(
+                content
+                    .lines()
+                    .map(|line| line.trim())
+                    .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                    .map(|line| line.to_string()),
+            );
+        }
+
+        for directory in &self.directories {
+            let entries = fs::read_dir(directory)
+                .map_err(|err| format!("failed to read scopes from {}: {}", directory, err))?;
+            let mut names: Vec<String> = entries
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.path().is_dir())
+                .filter_map(|entry| entry.file_name().to_str().map(|name| name.to_string()))
+                .collect();
+            names.sort();
+            scopes.extend(names);
+        }
+
+        Ok(scopes)
+    }
+
+    /// Check whether the scope matches one of the allowed scopes.
+    fn is_allowed(&self, scopes: &[String], scope: &str) -> bool {
+        scopes.iter().any(|allowed| {
+            if self.case_insensitive {
+                glob_match(&allowed.to_lowercase(), &scope.to_lowercase())
+            } else {
+                glob_match(allowed, scope)
+            }
+        })
+    }
+}
+
+/// Check whether the text matches the glob pattern.
+/// `*` matches any sequence of characters and `?` matches any single character.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` in the pattern and the text matched by it.
+    let mut star: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = star {
+            // Let the last `*` match one more character.
+            p = star_p + 1;
+            t = star_t + 1;
+            star = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+/// ScopeEnum represents the scope-enum rule.
+impl Rule for ScopeEnum {
+    const NAME: &'static str = "scope-enum";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        match self.allowed_scopes() {
+            Ok(scopes) => {
+                let scope = message
+                    .scopes(self.delimiter.as_deref())
+                    .into_iter()
+                    .find(|scope| !self.is_allowed(&scopes, scope))
+                    .unwrap_or_default();
+                format!(
+                    "scope {} is not allowed. Only {:?} are allowed",
+                    scope, scopes
+                )
+            }
+            Err(err) => err,
+        }
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        // Empty scopes are governed by the scope-empty rule.
+        let scopes = message.scopes(self.delimiter.as_deref());
+        if scopes.is_empty() {
+            return None;
+        }
+
+        match self.allowed_scopes() {
+            Ok(allowed) if scopes.iter().all(|scope| self.is_allowed(&allowed, scope)) => None,
+            _ => Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            }),
+        }
+    }
+}
+
+/// Default implementation of ScopeEnum.
+impl Default for ScopeEnum {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            allowed: vec![],
+            case_insensitive: false,
+            directories: vec![],
+            file: None,
+            delimiter: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allowed_scope() {
+        let rule = ScopeEnum {
+            allowed: vec!["api".to_string(), "ui".to_string()],
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: Some("add new endpoint".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(api): add new endpoint".to_string(),
+            scope: Some("api".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(api): add new endpoint".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_disallowed_scope() {
+        let rule = ScopeEnum {
+            allowed: vec!["api".to_string(), "ui".to_string()],
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: Some("bump regex".to_string()),
+            footers: None,
+            r#type: Some("chore".to_string()),
+            raw: "chore(deps): bump regex".to_string(),
+            scope: Some("deps".to_string()),
+            separator: ":".to_string(),
+            subject: Some("chore(deps): bump regex".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "scope deps is not allowed. Only [\"api\", \"ui\"] are allowed".to_string()
+        );
+    }
+
+    #[test]
+    fn test_multiple_scopes() {
+        let rule = ScopeEnum {
+            allowed: vec!["api".to_string(), "ui".to_string()],
+            delimiter: Some(",".to_string()),
+            ..Default::default()
+        };
+
+        let message = Message::new("feat(api,ui): x".to_string());
+        assert!(rule.validate(&message).is_none());
+
+        let message = Message::new("feat(api,deps): x".to_string());
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(
+            violation.unwrap().message,
+            "scope deps is not allowed. Only [\"api\", \"ui\"] are allowed".to_string()
+        );
+    }
+
+    #[test]
+    fn test_multiple_scopes_without_delimiter() {
+        let rule = ScopeEnum {
+            allowed: vec!["api".to_string(), "ui".to_string()],
+            ..Default::default()
+        };
+
+        let message = Message::new("feat(api,ui): x".to_string());
+        assert!(rule.validate(&message).is_some());
+    }
+
+    #[test]
+    fn test_empty_scope() {
+        let rule = ScopeEnum {
+            allowed: vec!["api".to_string(), "ui".to_string()],
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: Some("add new endpoint".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat: add new endpoint".to_string(),
+            scope: None,
+            separator: ":".to_string(),
+            subject: Some("feat: add new endpoint".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_case_sensitive_scope() {
+        let rule = ScopeEnum {
+            allowed: vec!["api".to_string()],
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: Some("add new endpoint".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(API): add new endpoint".to_string(),
+            scope: Some("API".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(API): add new endpoint".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_some());
+    }
+
+    #[test]
+    fn test_case_insensitive_scope() {
+        let rule = ScopeEnum {
+            allowed: vec!["api".to_string()],
+            case_insensitive: true,
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: Some("add new endpoint".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(API): add new endpoint".to_string(),
+            scope: Some("API".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(API): add new endpoint".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_glob_scope() {
+        let rule = ScopeEnum {
+            allowed: vec!["pkg-*".to_string()],
+            ..Default::default()
+        };
+
+        let message = Message::new("feat(pkg-cli): add new flag".to_string());
+        assert!(rule.validate(&message).is_none());
+
+        let message = Message::new("feat(cli): add new flag".to_string());
+        assert_eq!(
+            rule.validate(&message).unwrap().message,
+            "scope cli is not allowed. Only [\"pkg-*\"] are allowed".to_string()
+        );
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("pkg-*", "pkg-cli"));
+        assert!(glob_match("*-cli", "pkg-cli"));
+        assert!(glob_match("p?g-*i", "pkg-cli"));
+        assert!(glob_match("*", "cli"));
+        assert!(!glob_match("pkg-*", "cli"));
+        assert!(!glob_match("pkg-?", "pkg-cli"));
+    }
+
+    #[test]
+    fn test_scopes_from_file() {
+        let dir =
+            std::env::temp_dir().join(format!("commitlint-scope-enum-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("scopes.txt");
+        fs::write(&path, "# packages\napi\n\nui\n").unwrap();
+
+        let rule = ScopeEnum {
+            file: Some(path.to_str().unwrap().to_string()),
+            ..Default::default()
+        };
+
+        let message = Message::new("feat(ui): add new button".to_string());
+        assert!(rule.validate(&message).is_none());
+
+        let message = Message::new("chore(deps): bump regex".to_string());
+        assert_eq!(
+            rule.validate(&message).unwrap().message,
+            "scope deps is not allowed. Only [\"api\", \"ui\"] are allowed".to_string()
+        );
+
+        // The file is read again at lint time, so that the new scopes are allowed.
+        fs::write(&path, "api\nui\ndeps\n").unwrap();
+        assert!(rule.validate(&message).is_none());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_scopes_from_directories() {
+        let dir =
+            std::env::temp_dir().join(format!("commitlint-scope-dirs-{}", std::process::id()));
+        fs::create_dir_all(dir.join("cli")).unwrap();
+        fs::create_dir_all(dir.join("web")).unwrap();
+        fs::write(dir.join("README.md"), "").unwrap();
+
+        let rule = ScopeEnum {
+            directories: vec![dir.to_str().unwrap().to_string()],
+            ..Default::default()
+        };
+
+        let message = Message::new("feat(web): add new page".to_string());
+        assert!(rule.validate(&message).is_none());
+
+        let message = Message::new("docs(README.md): fix typo".to_string());
+        assert_eq!(
+            rule.validate(&message).unwrap().message,
+            "scope README.md is not allowed. Only [\"cli\", \"web\"] are allowed".to_string()
+        );
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let rule = ScopeEnum {
+            file: Some("not-existing-scopes.txt".to_string()),
+            ..Default::default()
+        };
+        let message = Message::new("feat(api): add new endpoint".to_string());
+
+        let violation = rule.validate(&message).unwrap();
+        assert!(violation
+            .message
+            .starts_with("failed to read scopes from not-existing-scopes.txt: "));
+    }
+}
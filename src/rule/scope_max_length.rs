@@ -1,9 +1,9 @@
-use crate::{message::Message, result::Violation, rule::Rule};
+use crate::{message::Message, result::Violation, rule::Rule, width::display_width};
 use serde::{Deserialize, Serialize};
 
 use super::Level;
 
-/// ScopeMaxLength represents the description-max-length rule.
+/// ScopeMaxLength represents the scope-max-length rule.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ScopeMaxLength {
     /// Level represents the level of the rule.
@@ -21,29 +21,24 @@ impl Rule for ScopeMaxLength {
     const NAME: &'static str = "scope-max-length";
     const LEVEL: Level = Level::Error;
 
-    fn message(&self, _message: &Message) -> String {
-        format!("scope is longer than {} characters", self.length)
+    fn message(&self, message: &Message) -> String {
+        format!(
+            "scope is longer than {} characters (found {})",
+            self.length,
+            display_width(message.scope.as_deref().unwrap_or_default())
+        )
     }
 
     fn validate(&self, message: &Message) -> Option<Violation> {
+        // Note that the missing scope is checked by the scope-empty rule.
         match &message.scope {
-            Some(scope) => {
-                if scope.len() >= self.length {
-                    return Some(Violation {
-                        level: self.level.unwrap_or(Self::LEVEL),
-                        message: self.message(message),
-                    });
-                }
-            }
-            None => {
-                return Some(Violation {
-                    level: self.level.unwrap_or(Self::LEVEL),
-                    message: self.message(message),
-                })
-            }
+            Some(scope) if display_width(scope) > self.length => Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            }),
+            _ => None,
         }
-
-        None
     }
 }
 
@@ -74,6 +69,7 @@ mod tests {
             r#type: Some("feat".to_string()),
             raw: "feat(scope): desc".to_string(),
             scope: Some("scope".to_string()),
+            separator: ":".to_string(),
             subject: Some("feat(scope): desc".to_string()),
         };
 
@@ -93,6 +89,7 @@ mod tests {
             r#type: Some("feat".to_string()),
             raw: "feat(scope): I'm long description".to_string(),
             scope: Some("scope".to_string()),
+            separator: ":".to_string(),
             subject: None,
         };
 
@@ -101,7 +98,29 @@ mod tests {
         assert_eq!(violation.clone().unwrap().level, Level::Error);
         assert_eq!(
             violation.unwrap().message,
-            format!("scope is longer than {} characters", rule.length)
+            "scope is longer than 3 characters (found 5)".to_string()
         );
     }
+
+    #[test]
+    fn test_scope_at_limit() {
+        let rule = ScopeMaxLength {
+            length: 5,
+            ..Default::default()
+        };
+        let message = Message::new("feat(scope): desc".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_no_scope() {
+        let rule = ScopeMaxLength {
+            length: 3,
+            ..Default::default()
+        };
+        let message = Message::new("feat: desc".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
 }
@@ -0,0 +1,111 @@
+use crate::{message::Message, result::Violation, rule::Rule};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// ScopeNotType represents the scope-not-type rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ScopeNotType {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+}
+
+/// ScopeNotType represents the scope-not-type rule.
+impl Rule for ScopeNotType {
+    const NAME: &'static str = "scope-not-type";
+    const LEVEL: Level = Level::Warning;
+
+    fn message(&self, message: &Message) -> String {
+        format!(
+            "scope must not be the same as the type {}",
+            message.r#type.as_deref().unwrap_or_default()
+        )
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if let (Some(r#type), Some(scope)) = (&message.r#type, &message.scope) {
+            if !scope.is_empty() && scope.eq_ignore_ascii_case(r#type) {
+                return Some(Violation {
+                    level: self.level.unwrap_or(Self::LEVEL),
+                    message: self.message(message),
+                    rule: Self::NAME.to_string(),
+                });
+            }
+        }
+
+        None
+    }
+}
+
+/// Default implementation of ScopeNotType.
+impl Default for ScopeNotType {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scope_same_as_type() {
+        let rule = ScopeNotType::default();
+        let message = Message {
+            body: None,
+            description: Some("x".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(feat): x".to_string(),
+            scope: Some("feat".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(feat): x".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Warning);
+        assert_eq!(
+            violation.unwrap().message,
+            "scope must not be the same as the type feat".to_string()
+        );
+    }
+
+    #[test]
+    fn test_scope_different_from_type() {
+        let rule = ScopeNotType::default();
+        let message = Message {
+            body: None,
+            description: Some("x".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(api): x".to_string(),
+            scope: Some("api".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(api): x".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_scope_same_as_type_ignoring_case() {
+        let rule = ScopeNotType::default();
+        let message = Message::new("fix(Fix): x".to_string());
+
+        assert!(rule.validate(&message).is_some());
+    }
+
+    #[test]
+    fn test_without_scope() {
+        let rule = ScopeNotType::default();
+        let message = Message::new("feat: x".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{message::Message, result::Violation, rule::Rule};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// ScopeRequiredForTypes represents the scope-required-for-types rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ScopeRequiredForTypes {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Types represents the types which require the scope.
+    #[serde(default = "default_types")]
+    types: Vec<String>,
+}
+
+/// Types which require the scope by default.
+fn default_types() -> Vec<String> {
+    vec!["feat".to_string(), "fix".to_string()]
+}
+
+/// ScopeRequiredForTypes represents the scope-required-for-types rule.
+impl Rule for ScopeRequiredForTypes {
+    const NAME: &'static str = "scope-required-for-types";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        format!(
+            "scope is required for type {}",
+            message.r#type.as_deref().unwrap_or_default()
+        )
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        let r#type = message.r#type.as_ref()?;
+        if !self.types.contains(r#type) {
+            return None;
+        }
+
+        let is_empty = match &message.scope {
+            None => true,
+            Some(scope) => scope.trim().is_empty(),
+        };
+
+        if is_empty {
+            return Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            });
+        }
+
+        None
+    }
+}
+
+/// Default implementation of ScopeRequiredForTypes.
+impl Default for ScopeRequiredForTypes {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            types: default_types(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scoped_type() {
+        let rule = ScopeRequiredForTypes::default();
+        let message = Message {
+            body: None,
+            description: Some("add new endpoint".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(api): add new endpoint".to_string(),
+            scope: Some("api".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(api): add new endpoint".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_unscoped_type() {
+        let rule = ScopeRequiredForTypes::default();
+        let message = Message {
+            body: None,
+            description: Some("add new endpoint".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat: add new endpoint".to_string(),
+            scope: None,
+            separator: ":".to_string(),
+            subject: Some("feat: add new endpoint".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "scope is required for type feat".to_string()
+        );
+    }
+
+    #[test]
+    fn test_other_type() {
+        let rule = ScopeRequiredForTypes::default();
+        let message = Message {
+            body: None,
+            description: Some("fix typo".to_string()),
+            footers: None,
+            r#type: Some("docs".to_string()),
+            raw: "docs: fix typo".to_string(),
+            scope: None,
+            separator: ":".to_string(),
+            subject: Some("docs: fix typo".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+}
@@ -0,0 +1,181 @@
+use crate::{message::Message, result::Violation, rule::Rule};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// SignedOffBy represents the signed-off-by rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SignedOffBy {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Value represents the token of the sign-off footer.
+    #[serde(default = "default_value")]
+    value: String,
+}
+
+/// Default token of the sign-off footer added by `git commit -s`.
+fn default_value() -> String {
+    "Signed-off-by:".to_string()
+}
+
+impl SignedOffBy {
+    /// Check whether the message is signed off.
+    /// Note that multiple sign-offs are allowed.
+    fn is_signed_off(&self, message: &Message) -> bool {
+        match &message.footers {
+            Some(footers) => footers
+                .iter()
+                .any(|footer| footer.to_string().starts_with(&self.value)),
+            // Fall back to scan the lines after the header.
+            None => message
+                .raw
+                .lines()
+                .skip(1)
+                .any(|line| line.trim().starts_with(&self.value)),
+        }
+    }
+}
+
+/// SignedOffBy represents the signed-off-by rule.
+impl Rule for SignedOffBy {
+    const NAME: &'static str = "signed-off-by";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, _message: &Message) -> String {
+        format!(
+            "message must be signed off with {:?}. Add it with `git commit -s`",
+            self.value
+        )
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if !self.is_signed_off(message) {
+            return Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            });
+        }
+
+        None
+    }
+}
+
+/// Default implementation of SignedOffBy.
+impl Default for SignedOffBy {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            value: default_value(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message::Footer;
+
+    #[test]
+    fn test_signed_off() {
+        let rule = SignedOffBy::default();
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: Some(vec![Footer {
+                token: "Signed-off-by".to_string(),
+                value: "Keke <keke@example.com>".to_string(),
+                separator: ':',
+            }]),
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag
+
+Signed-off-by: Keke <keke@example.com>"
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_not_signed_off() {
+        let rule = SignedOffBy::default();
+        let message = Message {
+            body: Some("Hello world".to_string()),
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag
+
+Hello world"
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "message must be signed off with \"Signed-off-by:\". Add it with `git commit -s`"
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_multiple_sign_offs() {
+        let rule = SignedOffBy::default();
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: Some(vec![
+                Footer {
+                    token: "Signed-off-by".to_string(),
+                    value: "Keke <keke@example.com>".to_string(),
+                    separator: ':',
+                },
+                Footer {
+                    token: "Signed-off-by".to_string(),
+                    value: "Z <z@example.com>".to_string(),
+                    separator: ':',
+                },
+            ]),
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag
+
+Signed-off-by: Keke <keke@example.com>
+Signed-off-by: Z <z@example.com>"
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_signed_off_without_footers() {
+        let rule = SignedOffBy::default();
+        let message = Message::new(
+            "feat(cli): add new flag
+
+Hello world
+Signed-off-by: Keke <keke@example.com>
+
+Note that I'm the last paragraph."
+                .to_string(),
+        );
+
+        assert!(rule.validate(&message).is_none());
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{message::Message, pattern, result::Violation, rule::Rule};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// SubjectAllowedChars represents the subject-allowed-chars rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SubjectAllowedChars {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Allowed represents the regular expression matching a single allowed character,
+    /// e.g. `[ -~]` for the printable ASCII characters.
+    #[serde(default = "default_allowed")]
+    allowed: String,
+}
+
+/// Default regular expression allowing the printable ASCII characters.
+fn default_allowed() -> String {
+    "[ -~]".to_string()
+}
+
+impl SubjectAllowedChars {
+    /// Find the characters of the subject not matching the allowed characters.
+    /// Each character is reported once in the order of appearance.
+    fn disallowed(&self, regex: &Regex, subject: &str) -> Vec<char> {
+        let mut found = Vec::new();
+        let mut buf = [0; 4];
+        for c in subject.chars() {
+            let allowed = regex
+                .find(c.encode_utf8(&mut buf))
+                .is_some_and(|m| m.len() == c.len_utf8());
+            if !allowed && !found.contains(&c) {
+                found.push(c);
+            }
+        }
+        found
+    }
+}
+
+/// SubjectAllowedChars represents the subject-allowed-chars rule.
+impl Rule for SubjectAllowedChars {
+    const NAME: &'static str = "subject-allowed-chars";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        let found = match (pattern::compile(&self.allowed), &message.subject) {
+            (Ok(regex), Some(subject)) => self.disallowed(&regex, subject),
+            _ => Vec::new(),
+        };
+
+        format!(
+            "subject must only contain characters matching {} but found {:?}",
+            self.allowed, found
+        )
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        let regex = match pattern::compile(&self.allowed) {
+            Ok(regex) => regex,
+            Err(err) => {
+                return Some(Violation {
+                    level: self.level.unwrap_or(Self::LEVEL),
+                    message: err.to_string(),
+                    rule: Self::NAME.to_string(),
+                });
+            }
+        };
+
+        if let Some(subject) = &message.subject {
+            if !self.disallowed(&regex, subject).is_empty() {
+                return Some(Violation {
+                    level: self.level.unwrap_or(Self::LEVEL),
+                    message: self.message(message),
+                    rule: Self::NAME.to_string(),
+                });
+            }
+        }
+
+        None
+    }
+}
+
+/// Default implementation of SubjectAllowedChars.
+impl Default for SubjectAllowedChars {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            allowed: default_allowed(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ascii_subject() {
+        let rule = SubjectAllowedChars::default();
+
+        let message = Message::new("feat(cli): add `--fix` flag (#123)".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_tab_in_subject() {
+        let rule = SubjectAllowedChars::default();
+
+        let message = Message::new("feat(cli): add\tnew flag".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "subject must only contain characters matching [ -~] but found ['\\t']".to_string()
+        );
+    }
+
+    #[test]
+    fn test_non_ascii_subject() {
+        let rule = SubjectAllowedChars::default();
+
+        let message = Message::new("feat: add café and café ✨".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(
+            violation.unwrap().message,
+            "subject must only contain characters matching [ -~] but found ['é', '✨']".to_string()
+        );
+    }
+
+    #[test]
+    fn test_custom_allowed() {
+        let rule = SubjectAllowedChars {
+            allowed: r"[\p{L}\p{N} :()-]".to_string(),
+            ..Default::default()
+        };
+
+        let message = Message::new("feat: add café".to_string());
+        assert!(rule.validate(&message).is_none());
+
+        let message = Message::new("feat: add café!".to_string());
+        assert!(rule.validate(&message).is_some());
+    }
+
+    #[test]
+    fn test_invalid_regex() {
+        let rule = SubjectAllowedChars {
+            allowed: r"[".to_string(),
+            ..Default::default()
+        };
+
+        let message = Message::new("feat: add new flag".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert!(violation.unwrap().message.contains("regex parse error"));
+    }
+}
@@ -0,0 +1,319 @@
+use crate::{
+    case::{self, Case},
+    message::Message,
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// SubjectCase represents the subject-case rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SubjectCase {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Allowed represents the allowed cases of the subject.
+    /// Available cases are `lower-case`, `upper-case`, `sentence-case`, `start-case`, `camel-case`, `kebab-case`, `snake-case` and `train-case`.
+    #[serde(
+        default = "default_allowed",
+        deserialize_with = "case::deserialize_names"
+    )]
+    allowed: Vec<String>,
+
+    /// FirstLetter represents whether only the first letter of the description is checked for the `sentence-case`.
+    /// The rest of the description is not constrained, e.g. `Add OAuth login` is allowed.
+    #[serde(default)]
+    first_letter: bool,
+}
+
+/// Subjects are in lower case by default.
+fn default_allowed() -> Vec<String> {
+    vec!["lower-case".to_string()]
+}
+
+impl SubjectCase {
+    /// Check whether the description is written in the case.
+    fn matches(&self, case: Case, description: &str) -> bool {
+        match case {
+            Case::Sentence if self.first_letter => description
+                .chars()
+                .find(|c| c.is_alphabetic())
+                .is_some_and(|c| c.is_uppercase()),
+            _ => case.matches(description),
+        }
+    }
+}
+
+/// SubjectCase represents the subject-case rule.
+impl Rule for SubjectCase {
+    const NAME: &'static str = "subject-case";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        let found = message
+            .description
+            .as_deref()
+            .and_then(case::detect)
+            .map(|case| case.name())
+            .unwrap_or("mixed-case");
+
+        format!(
+            "subject must be in one of {:?} but found {}",
+            self.allowed, found
+        )
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        // The description is used as the subject so that the type and the scope
+        // prefixes are not counted when determining the case.
+        let description = match &message.description {
+            Some(description) if !description.trim().is_empty() => description,
+            _ => return None,
+        };
+
+        let matched = self
+            .allowed
+            .iter()
+            .filter_map(|name| Case::from_name(name))
+            .any(|case| self.matches(case, description));
+
+        if !matched {
+            return Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            });
+        }
+
+        None
+    }
+}
+
+/// Default implementation of SubjectCase.
+impl Default for SubjectCase {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            allowed: default_allowed(),
+            first_letter: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lower_case() {
+        let rule = SubjectCase {
+            allowed: vec!["lower-case".to_string()],
+            ..Default::default()
+        };
+
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(scope): add new flag".to_string(),
+            scope: Some("scope".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(scope): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_upper_case() {
+        let rule = SubjectCase {
+            allowed: vec!["upper-case".to_string()],
+            ..Default::default()
+        };
+
+        let message = Message {
+            body: None,
+            description: Some("ADD NEW FLAG".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(scope): ADD NEW FLAG".to_string(),
+            scope: Some("scope".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(scope): ADD NEW FLAG".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_sentence_case() {
+        let rule = SubjectCase {
+            allowed: vec!["sentence-case".to_string()],
+            ..Default::default()
+        };
+
+        let message = Message {
+            body: None,
+            description: Some("Add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(scope): Add new flag".to_string(),
+            scope: Some("scope".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(scope): Add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_start_case() {
+        let rule = SubjectCase {
+            allowed: vec!["start-case".to_string()],
+            ..Default::default()
+        };
+
+        let message = Message {
+            body: None,
+            description: Some("Add New Flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(scope): Add New Flag".to_string(),
+            scope: Some("scope".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(scope): Add New Flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_camel_case() {
+        let rule = SubjectCase {
+            allowed: vec!["camel-case".to_string()],
+            ..Default::default()
+        };
+
+        let message = Message {
+            body: None,
+            description: Some("addNewFlag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(scope): addNewFlag".to_string(),
+            scope: Some("scope".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(scope): addNewFlag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_empty_subject() {
+        let rule = SubjectCase::default();
+
+        let message = Message {
+            body: None,
+            description: Some("".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(scope): ".to_string(),
+            scope: Some("scope".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(scope): ".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_mixed_case() {
+        let rule = SubjectCase {
+            allowed: vec!["lower-case".to_string(), "sentence-case".to_string()],
+            ..Default::default()
+        };
+
+        let message = Message {
+            body: None,
+            description: Some("aDD nEW Flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(scope): aDD nEW Flag".to_string(),
+            scope: Some("scope".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(scope): aDD nEW Flag".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "subject must be in one of [\"lower-case\", \"sentence-case\"] but found mixed-case"
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_unexpected_case() {
+        let rule = SubjectCase::default();
+
+        let message = Message {
+            body: None,
+            description: Some("Add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(scope): Add new flag".to_string(),
+            scope: Some("scope".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(scope): Add new flag".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(
+            violation.unwrap().message,
+            "subject must be in one of [\"lower-case\"] but found sentence-case".to_string()
+        );
+    }
+
+    #[test]
+    fn test_first_letter() {
+        let rule = SubjectCase {
+            allowed: vec!["sentence-case".to_string()],
+            first_letter: true,
+            ..Default::default()
+        };
+
+        let message = Message::new("feat: Add login".to_string());
+        assert!(rule.validate(&message).is_none());
+
+        let message = Message::new("feat: Add OAuth login".to_string());
+        assert!(rule.validate(&message).is_none());
+
+        let message = Message::new("feat: add login".to_string());
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(
+            violation.unwrap().message,
+            "subject must be in one of [\"sentence-case\"] but found lower-case".to_string()
+        );
+    }
+
+    #[test]
+    fn test_sentence_case_without_first_letter() {
+        let rule = SubjectCase {
+            allowed: vec!["sentence-case".to_string()],
+            ..Default::default()
+        };
+
+        let message = Message::new("feat: Add OAuth login".to_string());
+        assert!(rule.validate(&message).is_some());
+    }
+}
@@ -11,6 +11,11 @@ pub struct SubjectEmpty {
     // Note that currently the default literal is not supported.
     // See: https://github.com/serde-rs/serde/issues/368
     level: Option<Level>,
+
+    /// Forbidden represents whether the subject must be empty.
+    /// If it is false, the subject must not be empty instead.
+    #[serde(default)]
+    forbidden: bool,
 }
 
 /// SubjectEmpty represents the subject-empty rule.
@@ -19,14 +24,29 @@ impl Rule for SubjectEmpty {
     const LEVEL: Level = Level::Error;
 
     fn message(&self, _message: &Message) -> String {
+        if self.forbidden {
+            return "subject is not empty".to_string();
+        }
+
         "subject is empty".to_string()
     }
 
     fn validate(&self, message: &Message) -> Option<Violation> {
-        if message.subject.is_none() {
+        // The description is used as the subject so that `feat:` without
+        // anything after the type is empty.
+        // Note that the subject only containing whitespace is empty.
+        let is_empty = match (&message.subject, &message.description) {
+            (Some(subject), Some(description)) => {
+                subject.trim().is_empty() || description.trim().is_empty()
+            }
+            _ => true,
+        };
+
+        if is_empty != self.forbidden {
             return Some(Violation {
                 level: self.level.unwrap_or(Self::LEVEL),
                 message: self.message(message),
+                rule: Self::NAME.to_string(),
             });
         }
 
@@ -39,6 +59,7 @@ impl Default for SubjectEmpty {
     fn default() -> Self {
         Self {
             level: Some(Self::LEVEL),
+            forbidden: false,
         }
     }
 }
@@ -60,6 +81,7 @@ mod tests {
 Hello world"
                 .to_string(),
             scope: Some("scope".to_string()),
+            separator: ":".to_string(),
             subject: Some("feat(scope): broadcast $destroy event on scope destruction".to_string()),
         };
 
@@ -79,15 +101,74 @@ Hello world"
 Hello world"
                 .to_string(),
             scope: Some("scope".to_string()),
+            separator: ":".to_string(),
             subject: None,
         };
 
         let violation = rule.validate(&message);
         assert!(violation.is_some());
         assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(violation.unwrap().message, "subject is empty".to_string());
+    }
+
+    #[test]
+    fn test_empty_description_after_type() {
+        let rule = SubjectEmpty::default();
+        let message = Message {
+            body: None,
+            description: Some("".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat:".to_string(),
+            scope: None,
+            separator: ":".to_string(),
+            subject: Some("feat:".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.unwrap().message, "subject is empty".to_string());
+    }
+
+    #[test]
+    fn test_whitespace_description() {
+        let rule = SubjectEmpty::default();
+        let message = Message {
+            body: None,
+            description: Some("   ".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat:    ".to_string(),
+            scope: None,
+            separator: ":".to_string(),
+            subject: Some("feat:    ".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_some());
+    }
+
+    #[test]
+    fn test_forbidden_subject() {
+        let rule = SubjectEmpty {
+            forbidden: true,
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: Some("add thing".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat: add thing".to_string(),
+            scope: None,
+            separator: ":".to_string(),
+            subject: Some("feat: add thing".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
         assert_eq!(
             violation.unwrap().message,
-            "subject is empty".to_string()
+            "subject is not empty".to_string()
         );
     }
 }
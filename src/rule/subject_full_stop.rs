@@ -0,0 +1,258 @@
+use crate::{message::Message, result::Violation, rule::Rule};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// SubjectFullStop represents the subject-full-stop rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SubjectFullStop {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Value represents the full stop character(s) of the subject.
+    #[serde(default = "default_value")]
+    value: String,
+
+    /// Forbidden represents whether the subject must not end with the full stop.
+    /// If it is false, the subject must end with the full stop instead.
+    #[serde(default = "default_forbidden")]
+    forbidden: bool,
+}
+
+/// Default full stop of the subject.
+fn default_value() -> String {
+    ".".to_string()
+}
+
+/// Full stops are forbidden by default.
+fn default_forbidden() -> bool {
+    true
+}
+
+/// SubjectFullStop represents the subject-full-stop rule.
+impl Rule for SubjectFullStop {
+    const NAME: &'static str = "subject-full-stop";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, _message: &Message) -> String {
+        if self.forbidden {
+            return format!("subject must not end with {:?}", self.value);
+        }
+
+        format!("subject must end with {:?}", self.value)
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if let Some(description) = &message.description {
+            if description.ends_with(&self.value) == self.forbidden {
+                return Some(Violation {
+                    level: self.level.unwrap_or(Self::LEVEL),
+                    message: self.message(message),
+                    rule: Self::NAME.to_string(),
+                });
+            }
+        }
+
+        None
+    }
+
+    fn fix(&self, message: &mut Message) {
+        if self.value.is_empty() {
+            return;
+        }
+
+        // Only the first line is rewritten so that the rest of the message and
+        // the line endings are kept as they are.
+        let (subject, rest) = match message.raw.find('\n') {
+            Some(index) => message.raw.split_at(index),
+            None => (message.raw.as_str(), ""),
+        };
+        let (subject, ending) = match subject.strip_suffix('\r') {
+            Some(subject) => (subject, "\r"),
+            None => (subject, ""),
+        };
+
+        let mut fixed = subject.trim_end();
+        while let Some(stripped) = fixed.strip_suffix(self.value.as_str()) {
+            fixed = stripped;
+        }
+
+        let raw = if self.forbidden {
+            format!("{}{}{}", fixed, ending, rest)
+        } else {
+            format!("{}{}{}{}", fixed, self.value, ending, rest)
+        };
+
+        *message = message.with_raw(raw);
+    }
+}
+
+/// Default implementation of SubjectFullStop.
+impl Default for SubjectFullStop {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            value: default_value(),
+            forbidden: default_forbidden(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_without_full_stop() {
+        let rule = SubjectFullStop::default();
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag".to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_with_full_stop() {
+        let rule = SubjectFullStop::default();
+        let message = Message {
+            body: None,
+            description: Some("add new flag.".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag.".to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag.".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "subject must not end with \".\"".to_string()
+        );
+    }
+
+    #[test]
+    fn test_without_required_full_stop() {
+        let rule = SubjectFullStop {
+            forbidden: false,
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag".to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "subject must end with \".\"".to_string()
+        );
+    }
+
+    #[test]
+    fn test_with_required_full_stop() {
+        let rule = SubjectFullStop {
+            value: "!".to_string(),
+            forbidden: false,
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: Some("add new flag!".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag!".to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag!".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_fix_required_full_stop() {
+        let rule = SubjectFullStop {
+            forbidden: false,
+            ..Default::default()
+        };
+        let mut message = Message::new("feat(cli): add new flag".to_string());
+
+        rule.fix(&mut message);
+        assert_eq!(message.raw, "feat(cli): add new flag.".to_string());
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_fix() {
+        let rule = SubjectFullStop::default();
+        let mut message = Message::new(
+            "feat(cli): add new flag.
+
+Hello, I'm the body."
+                .to_string(),
+        );
+
+        rule.fix(&mut message);
+        assert_eq!(
+            message.raw,
+            "feat(cli): add new flag
+
+Hello, I'm the body."
+                .to_string()
+        );
+        assert_eq!(message.description, Some("add new flag".to_string()));
+        assert_eq!(message.body, Some("Hello, I'm the body.".to_string()));
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_fix_with_separator() {
+        let rule = SubjectFullStop::default();
+        let mut message = Message::with_separator("feat(cli)/ add new flag.".to_string(), "/");
+
+        rule.fix(&mut message);
+        assert_eq!(message.raw, "feat(cli)/ add new flag".to_string());
+        assert_eq!(message.r#type, Some("feat".to_string()));
+        assert_eq!(message.description, Some("add new flag".to_string()));
+    }
+
+    #[test]
+    fn test_fix_keeps_line_endings() {
+        let rule = SubjectFullStop::default();
+        let mut message = Message::new("feat: add thing.\r\n\r\nbody line.\r\n".to_string());
+
+        rule.fix(&mut message);
+        assert_eq!(
+            message.raw,
+            "feat: add thing\r\n\r\nbody line.\r\n".to_string()
+        );
+
+        let mut message = Message::new("feat: add thing.\n\nbody line\n".to_string());
+
+        rule.fix(&mut message);
+        assert_eq!(message.raw, "feat: add thing\n\nbody line\n".to_string());
+    }
+}
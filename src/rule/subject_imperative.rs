@@ -0,0 +1,180 @@
+use crate::{message::Message, result::Violation, rule::Rule};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// Words which are not in imperative mood but are not caught by the suffixes.
+const NON_IMPERATIVE_WORDS: [&str; 12] = [
+    "adds", "bumps", "changes", "creates", "deletes", "fixes", "improves", "makes", "moves",
+    "removes", "renames", "updates",
+];
+
+/// Imperative words which end with the default suffixes.
+const IMPERATIVE_WORDS: [&str; 11] = [
+    "bring", "embed", "feed", "need", "ping", "proceed", "seed", "shed", "speed", "spring",
+    "string",
+];
+
+/// SubjectImperative represents the subject-imperative rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SubjectImperative {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// ForbiddenSuffixes represents the suffixes of the first word which are not in imperative mood.
+    #[serde(default = "default_forbidden_suffixes")]
+    forbidden_suffixes: Vec<String>,
+}
+
+/// Suffixes of the past tense and the present participle.
+fn default_forbidden_suffixes() -> Vec<String> {
+    vec!["ed".to_string(), "ing".to_string()]
+}
+
+impl SubjectImperative {
+    /// Find the first word of the subject if it is not in imperative mood.
+    /// Note that this is a heuristic and may have false positives.
+    fn find_non_imperative(&self, message: &Message) -> Option<String> {
+        let word = message
+            .description
+            .as_deref()?
+            .split_whitespace()
+            .next()?
+            .to_lowercase();
+
+        if IMPERATIVE_WORDS.contains(&word.as_str()) {
+            return None;
+        }
+
+        let forbidden = NON_IMPERATIVE_WORDS.contains(&word.as_str())
+            || self
+                .forbidden_suffixes
+                .iter()
+                .any(|suffix| word.len() > suffix.len() && word.ends_with(suffix.as_str()));
+
+        forbidden.then_some(word)
+    }
+}
+
+/// SubjectImperative represents the subject-imperative rule.
+impl Rule for SubjectImperative {
+    const NAME: &'static str = "subject-imperative";
+    const LEVEL: Level = Level::Warning;
+
+    fn message(&self, message: &Message) -> String {
+        match self.find_non_imperative(message) {
+            Some(word) => format!(
+                "subject should be in imperative mood but starts with {:?}",
+                word
+            ),
+            None => "subject should be in imperative mood".to_string(),
+        }
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if self.find_non_imperative(message).is_some() {
+            return Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            });
+        }
+
+        None
+    }
+}
+
+/// Default implementation of SubjectImperative.
+impl Default for SubjectImperative {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            forbidden_suffixes: default_forbidden_suffixes(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_imperative_subject() {
+        let rule = SubjectImperative::default();
+        let message = Message {
+            body: None,
+            description: Some("add feature".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat: add feature".to_string(),
+            scope: None,
+            separator: ":".to_string(),
+            subject: Some("feat: add feature".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_non_imperative_word() {
+        let rule = SubjectImperative::default();
+        let message = Message {
+            body: None,
+            description: Some("adds feature".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat: adds feature".to_string(),
+            scope: None,
+            separator: ":".to_string(),
+            subject: Some("feat: adds feature".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Warning);
+        assert_eq!(
+            violation.unwrap().message,
+            "subject should be in imperative mood but starts with \"adds\"".to_string()
+        );
+    }
+
+    #[test]
+    fn test_forbidden_suffixes() {
+        let rule = SubjectImperative::default();
+
+        for description in ["fixed typo", "Updating docs"] {
+            let message = Message {
+                body: None,
+                description: Some(description.to_string()),
+                footers: None,
+                r#type: Some("fix".to_string()),
+                raw: format!("fix: {}", description),
+                scope: None,
+                separator: ":".to_string(),
+                subject: Some(format!("fix: {}", description)),
+            };
+
+            assert!(rule.validate(&message).is_some(), "{}", description);
+        }
+    }
+
+    #[test]
+    fn test_imperative_word_with_suffix() {
+        let rule = SubjectImperative::default();
+        let message = Message {
+            body: None,
+            description: Some("embed fonts".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat: embed fonts".to_string(),
+            scope: None,
+            separator: ":".to_string(),
+            subject: Some("feat: embed fonts".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+}
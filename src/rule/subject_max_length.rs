@@ -0,0 +1,133 @@
+use crate::{message::Message, result::Violation, rule::Rule, width::display_width};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// SubjectMaxLength represents the subject-max-length rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SubjectMaxLength {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Length represents the maximum length of the subject.
+    length: usize,
+}
+
+/// SubjectMaxLength represents the subject-max-length rule.
+impl Rule for SubjectMaxLength {
+    const NAME: &'static str = "subject-max-length";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        format!(
+            "subject is longer than {} characters (found {})",
+            self.length,
+            display_width(message.description.as_deref().unwrap_or_default())
+        )
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        // The description is used as the subject so that the type and the scope
+        // prefixes are not counted.
+        if let Some(description) = &message.description {
+            if display_width(description) > self.length {
+                return Some(Violation {
+                    level: self.level.unwrap_or(Self::LEVEL),
+                    message: self.message(message),
+                    rule: Self::NAME.to_string(),
+                });
+            }
+        }
+
+        None
+    }
+}
+
+/// Default implementation of SubjectMaxLength.
+impl Default for SubjectMaxLength {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            length: 72,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_subject() {
+        let rule = SubjectMaxLength {
+            length: 12,
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag".to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_long_subject() {
+        let rule = SubjectMaxLength {
+            length: 12,
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: Some("add new flag for brand new feature".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag for brand new feature".to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag for brand new feature".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "subject is longer than 12 characters (found 34)".to_string()
+        );
+    }
+
+    #[test]
+    fn test_wide_subject() {
+        let rule = SubjectMaxLength {
+            length: 12,
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: Some("新しいフラグを追加".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): 新しいフラグを追加".to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): 新しいフラグを追加".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(
+            violation.unwrap().message,
+            "subject is longer than 12 characters (found 18)".to_string()
+        );
+    }
+}
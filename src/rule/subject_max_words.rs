@@ -0,0 +1,98 @@
+use crate::{message::Message, result::Violation, rule::Rule};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// SubjectMaxWords represents the subject-max-words rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SubjectMaxWords {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Count represents the maximum number of the words in the subject.
+    count: usize,
+}
+
+/// Count the whitespace-separated words of the subject.
+fn count_words(message: &Message) -> usize {
+    message
+        .description
+        .as_deref()
+        .unwrap_or_default()
+        .split_whitespace()
+        .count()
+}
+
+/// SubjectMaxWords represents the subject-max-words rule.
+impl Rule for SubjectMaxWords {
+    const NAME: &'static str = "subject-max-words";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        format!(
+            "subject has more than {} words (found {})",
+            self.count,
+            count_words(message)
+        )
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        // The description is used as the subject so that the type and the scope
+        // prefixes are not counted.
+        if count_words(message) > self.count {
+            return Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            });
+        }
+
+        None
+    }
+}
+
+/// Default implementation of SubjectMaxWords.
+impl Default for SubjectMaxWords {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            count: 10,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subject_at_limit() {
+        let rule = SubjectMaxWords {
+            count: 3,
+            ..Default::default()
+        };
+        let message = Message::new("feat(cli): add new  flag".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_subject_over_limit() {
+        let rule = SubjectMaxWords {
+            count: 3,
+            ..Default::default()
+        };
+        let message = Message::new("feat(cli): add new flag for brand new feature".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "subject has more than 3 words (found 7)".to_string()
+        );
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{message::Message, result::Violation, rule::Rule, width::display_width};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// SubjectMinLength represents the subject-min-length rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SubjectMinLength {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Length represents the minimum length of the subject.
+    length: usize,
+}
+
+/// SubjectMinLength represents the subject-min-length rule.
+impl Rule for SubjectMinLength {
+    const NAME: &'static str = "subject-min-length";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        format!(
+            "subject is shorter than {} characters (found {})",
+            self.length,
+            display_width(message.description.as_deref().unwrap_or_default())
+        )
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        // Note that the empty subject is checked by the subject-empty rule.
+        let description = match &message.description {
+            Some(description) if !description.trim().is_empty() => description,
+            _ => return None,
+        };
+
+        if display_width(description) < self.length {
+            return Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            });
+        }
+
+        None
+    }
+}
+
+/// Default implementation of SubjectMinLength.
+impl Default for SubjectMinLength {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            length: 10,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_long_subject() {
+        let rule = SubjectMinLength::default();
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag".to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_short_subject() {
+        let rule = SubjectMinLength::default();
+        let message = Message {
+            body: None,
+            description: Some("bug".to_string()),
+            footers: None,
+            r#type: Some("fix".to_string()),
+            raw: "fix: bug".to_string(),
+            scope: None,
+            separator: ":".to_string(),
+            subject: Some("fix: bug".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "subject is shorter than 10 characters (found 3)".to_string()
+        );
+    }
+
+    #[test]
+    fn test_empty_subject() {
+        let rule = SubjectMinLength::default();
+        let message = Message {
+            body: None,
+            description: Some("".to_string()),
+            footers: None,
+            r#type: Some("fix".to_string()),
+            raw: "fix:".to_string(),
+            scope: None,
+            separator: ":".to_string(),
+            subject: Some("fix:".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+}
@@ -0,0 +1,137 @@
+use crate::{message::Message, result::Violation, rule::Rule};
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+use super::Level;
+
+/// SubjectMoodWordlist represents the subject-mood-wordlist rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SubjectMoodWordlist {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// File represents the path to the file listing the allowed imperative verbs, one per line.
+    /// Blank lines and lines starting with `#` are skipped.
+    file: String,
+}
+
+impl SubjectMoodWordlist {
+    /// Get the allowed verbs in lowercase.
+    ///
+    /// Note that the file is read every time the subject is checked,
+    /// so that the changes of the list are followed without editing the config.
+    fn allowed_words(&self) -> Result<Vec<String>, String> {
+        let content = fs::read_to_string(&self.file)
+            .map_err(|err| format!("failed to read words from {}: {}", self.file, err))?;
+
+        Ok(content
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| line.to_lowercase())
+            .collect())
+    }
+}
+
+/// Get the first word of the subject in lowercase.
+fn first_word(message: &Message) -> Option<String> {
+    message
+        .description
+        .as_deref()?
+        .split_whitespace()
+        .next()
+        .map(|word| word.to_lowercase())
+}
+
+/// SubjectMoodWordlist represents the subject-mood-wordlist rule.
+impl Rule for SubjectMoodWordlist {
+    const NAME: &'static str = "subject-mood-wordlist";
+    const LEVEL: Level = Level::Warning;
+
+    fn message(&self, message: &Message) -> String {
+        match self.allowed_words() {
+            Ok(_) => format!(
+                "subject should start with a verb listed in {} but starts with {:?}",
+                self.file,
+                first_word(message).unwrap_or_default()
+            ),
+            Err(err) => err,
+        }
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        // Empty subjects are governed by the subject-empty rule.
+        let word = first_word(message)?;
+
+        match self.allowed_words() {
+            Ok(words) if words.contains(&word) => None,
+            _ => Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            }),
+        }
+    }
+}
+
+/// Default implementation of SubjectMoodWordlist.
+impl Default for SubjectMoodWordlist {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            file: ".commitlint-verbs".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_words_from_file() {
+        let dir =
+            std::env::temp_dir().join(format!("commitlint-mood-words-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("verbs.txt");
+        fs::write(&path, "# verbs\nadd\n\nfix\n").unwrap();
+
+        let rule = SubjectMoodWordlist {
+            file: path.to_str().unwrap().to_string(),
+            ..Default::default()
+        };
+
+        let message = Message::new("feat(cli): Add new flag".to_string());
+        assert!(rule.validate(&message).is_none());
+
+        let message = Message::new("feat(cli): added new flag".to_string());
+        let violation = rule.validate(&message).unwrap();
+        assert_eq!(violation.level, Level::Warning);
+        assert_eq!(
+            violation.message,
+            format!(
+                "subject should start with a verb listed in {} but starts with \"added\"",
+                path.display()
+            )
+        );
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let rule = SubjectMoodWordlist {
+            file: "not-existing-verbs.txt".to_string(),
+            ..Default::default()
+        };
+        let message = Message::new("feat(cli): add new flag".to_string());
+
+        let violation = rule.validate(&message).unwrap();
+        assert!(violation
+            .message
+            .starts_with("failed to read words from not-existing-verbs.txt: "));
+    }
+}
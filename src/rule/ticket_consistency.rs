@@ -0,0 +1,135 @@
+use std::collections::BTreeSet;
+
+use crate::{message::Message, pattern, result::Violation, rule::Rule};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// TicketConsistency represents the ticket-consistency rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TicketConsistency {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Pattern represents the regex matching the ticket IDs.
+    #[serde(default = "default_pattern")]
+    pattern: String,
+}
+
+/// Default regex matching the JIRA-style ticket IDs such as `#ABC-123`.
+pub(crate) fn default_pattern() -> String {
+    r"#[A-Z]+-\d+".to_string()
+}
+
+/// Find the distinct ticket IDs in the text in ascending order.
+fn find_tickets(regex: &regex::Regex, text: &str) -> BTreeSet<String> {
+    regex
+        .find_iter(text)
+        .map(|ticket| ticket.as_str().to_string())
+        .collect()
+}
+
+impl TicketConsistency {
+    /// Build the violation with the message.
+    fn violation(&self, message: String) -> Violation {
+        Violation {
+            level: self.level.unwrap_or(Self::LEVEL),
+            message,
+            rule: Self::NAME.to_string(),
+        }
+    }
+}
+
+/// TicketConsistency represents the ticket-consistency rule.
+impl Rule for TicketConsistency {
+    const NAME: &'static str = "ticket-consistency";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        match self.validate(message) {
+            Some(violation) => violation.message,
+            None => "ticket IDs of the body must be the same as the subject".to_string(),
+        }
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        let regex = match pattern::compile(&self.pattern) {
+            Ok(regex) => regex,
+            Err(err) => return Some(self.violation(err.to_string())),
+        };
+
+        let subject = find_tickets(&regex, message.subject.as_deref().unwrap_or_default());
+        let body = find_tickets(&regex, message.body.as_deref().unwrap_or_default());
+
+        // Note that the tickets only in either of them are not compared.
+        if subject.is_empty() || body.is_empty() || subject == body {
+            return None;
+        }
+
+        Some(self.violation(format!(
+            "ticket IDs of the body {:?} must be the same as the subject {:?}",
+            body, subject
+        )))
+    }
+}
+
+/// Default implementation of TicketConsistency.
+impl Default for TicketConsistency {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            pattern: default_pattern(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matching_tickets() {
+        let rule = TicketConsistency::default();
+
+        assert!(rule
+            .validate(&Message::new(
+                "feat(cli): #ABC-1 add new flag\n\nThis is for #ABC-1.".to_string()
+            ))
+            .is_none());
+        assert!(rule
+            .validate(&Message::new(
+                "feat(cli): #ABC-1 add new flag\n\nHello world".to_string()
+            ))
+            .is_none());
+    }
+
+    #[test]
+    fn test_mismatched_tickets() {
+        let rule = TicketConsistency::default();
+        let message =
+            Message::new("feat(cli): #ABC-1 add new flag\n\nThis is for #ABC-2.".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "ticket IDs of the body {\"#ABC-2\"} must be the same as the subject {\"#ABC-1\"}"
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_invalid_pattern() {
+        let rule = TicketConsistency {
+            pattern: "(".to_string(),
+            ..Default::default()
+        };
+        let message = Message::new("feat(cli): add new flag".to_string());
+
+        assert!(rule.validate(&message).is_some());
+    }
+}
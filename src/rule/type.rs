@@ -35,15 +35,11 @@ impl Rule for Type {
 
     fn validate(&self, message: &Message) -> Option<Violation> {
         match &message.r#type {
-            None => {
-                if self.options.is_empty() {
-                    return None;
-                }
+            None if self.options.is_empty() => {
+                return None;
             }
-            Some(r#type) if r#type.is_empty() => {
-                if self.options.is_empty() {
-                    return None;
-                }
+            Some(r#type) if r#type.is_empty() && self.options.is_empty() => {
+                return None;
             }
             Some(r#type) if self.options.contains(r#type) => {
                 return None;
@@ -54,6 +50,7 @@ impl Rule for Type {
         Some(Violation {
             level: self.level.unwrap_or(Self::LEVEL),
             message: self.message(message),
+            rule: Self::NAME.to_string(),
         })
     }
 }
@@ -86,6 +83,7 @@ mod tests {
                 r#type: None,
                 raw: "".to_string(),
                 scope: Some("".to_string()),
+                separator: ":".to_string(),
                 subject: None,
             };
 
@@ -104,6 +102,7 @@ mod tests {
                 r#type: None,
                 raw: "".to_string(),
                 scope: None,
+                separator: ":".to_string(),
                 subject: None,
             };
 
@@ -122,6 +121,7 @@ mod tests {
                 r#type: Some("feat".to_string()),
                 raw: "feat(web): broadcast $destroy event on scope destruction".to_string(),
                 scope: Some("web".to_string()),
+                separator: ":".to_string(),
                 subject: None,
             };
 
@@ -139,8 +139,10 @@ mod tests {
         use super::*;
         #[test]
         fn test_empty_type() {
-            let mut rule = Type::default();
-            rule.options = vec!["feat".to_string(), "chore".to_string()];
+            let rule = Type {
+                options: vec!["feat".to_string(), "chore".to_string()],
+                ..Default::default()
+            };
 
             let message = Message {
                 body: None,
@@ -149,6 +151,7 @@ mod tests {
                 r#type: None,
                 raw: "".to_string(),
                 scope: Some("".to_string()),
+                separator: ":".to_string(),
                 subject: None,
             };
 
@@ -163,8 +166,10 @@ mod tests {
 
         #[test]
         fn test_none_type() {
-            let mut rule = Type::default();
-            rule.options = vec!["feat".to_string(), "chore".to_string()];
+            let rule = Type {
+                options: vec!["feat".to_string(), "chore".to_string()],
+                ..Default::default()
+            };
 
             let message = Message {
                 body: None,
@@ -173,6 +178,7 @@ mod tests {
                 r#type: None,
                 raw: "".to_string(),
                 scope: None,
+                separator: ":".to_string(),
                 subject: None,
             };
 
@@ -187,8 +193,10 @@ mod tests {
 
         #[test]
         fn test_valid_type() {
-            let mut rule = Type::default();
-            rule.options = vec!["feat".to_string(), "chore".to_string()];
+            let rule = Type {
+                options: vec!["feat".to_string(), "chore".to_string()],
+                ..Default::default()
+            };
 
             let message = Message {
                 body: None,
@@ -197,6 +205,7 @@ mod tests {
                 r#type: Some("feat".to_string()),
                 raw: "feat(web): broadcast $destroy event on scope destruction".to_string(),
                 scope: Some("web".to_string()),
+                separator: ":".to_string(),
                 subject: None,
             };
 
@@ -205,8 +214,10 @@ mod tests {
 
         #[test]
         fn test_invalid_type() {
-            let mut rule = Type::default();
-            rule.options = vec!["feat".to_string(), "chore".to_string()];
+            let rule = Type {
+                options: vec!["feat".to_string(), "chore".to_string()],
+                ..Default::default()
+            };
 
             let message = Message {
                 body: None,
@@ -215,6 +226,7 @@ mod tests {
                 r#type: Some("invalid".to_string()),
                 raw: "invalid(web): broadcast $destroy event on scope destruction".to_string(),
                 scope: Some("web".to_string()),
+                separator: ":".to_string(),
                 subject: None,
             };
 
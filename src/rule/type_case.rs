@@ -0,0 +1,227 @@
+use crate::{
+    case::{self, Case},
+    message::Message,
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// TypeCase represents the type-case rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TypeCase {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Allowed represents the allowed cases of the type.
+    /// Available cases are the same as the subject-case rule.
+    #[serde(
+        default = "default_allowed",
+        deserialize_with = "case::deserialize_names"
+    )]
+    allowed: Vec<String>,
+
+    /// Strict represents whether the type must only contain lowercase letters and `-`.
+    /// Unlike the `lower-case`, the digits and the other symbols such as `_` are not allowed.
+    #[serde(default)]
+    strict: bool,
+}
+
+/// Types are in lower case by default.
+fn default_allowed() -> Vec<String> {
+    vec!["lower-case".to_string()]
+}
+
+/// Check whether the type only contains lowercase letters and `-`.
+fn is_strict_lowercase(r#type: &str) -> bool {
+    r#type.chars().all(|c| c.is_lowercase() || c == '-')
+}
+
+/// TypeCase represents the type-case rule.
+impl Rule for TypeCase {
+    const NAME: &'static str = "type-case";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        let r#type = message.r#type.as_deref().unwrap_or_default();
+        if self.strict {
+            return format!(
+                "type {} must only contain lowercase letters and \"-\"",
+                r#type
+            );
+        }
+
+        let found = case::detect(r#type)
+            .map(|case| case.name())
+            .unwrap_or("mixed-case");
+
+        format!(
+            "type {} must be in one of {:?} but found {}",
+            r#type, self.allowed, found
+        )
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        // Note that the missing type is checked by the type-empty rule.
+        let r#type = match &message.r#type {
+            Some(r#type) if !r#type.is_empty() => r#type,
+            _ => return None,
+        };
+
+        let matched = if self.strict {
+            is_strict_lowercase(r#type)
+        } else {
+            self.allowed
+                .iter()
+                .filter_map(|name| Case::from_name(name))
+                .any(|case| case.matches(r#type))
+        };
+
+        if !matched {
+            return Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            });
+        }
+
+        None
+    }
+}
+
+/// Default implementation of TypeCase.
+impl Default for TypeCase {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            allowed: default_allowed(),
+            strict: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lower_case() {
+        let rule = TypeCase::default();
+
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag".to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_no_type() {
+        let rule = TypeCase::default();
+
+        let message = Message {
+            body: None,
+            description: Some("Add new flag".to_string()),
+            footers: None,
+            r#type: None,
+            raw: "Add new flag".to_string(),
+            scope: None,
+            separator: ":".to_string(),
+            subject: Some("Add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_upper_case() {
+        let rule = TypeCase::default();
+
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("FEAT".to_string()),
+            raw: "FEAT(cli): add new flag".to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("FEAT(cli): add new flag".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "type FEAT must be in one of [\"lower-case\"] but found upper-case".to_string()
+        );
+    }
+
+    #[test]
+    fn test_upper_case_allowed() {
+        let rule = TypeCase {
+            allowed: vec!["upper-case".to_string()],
+            ..Default::default()
+        };
+
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("FEAT".to_string()),
+            raw: "FEAT(cli): add new flag".to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("FEAT(cli): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_strict() {
+        let rule = TypeCase {
+            strict: true,
+            ..Default::default()
+        };
+
+        let message = Message::new("feat(cli): add new flag".to_string());
+        assert!(rule.validate(&message).is_none());
+
+        let message = Message::new("Feat(cli): add new flag".to_string());
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "type Feat must only contain lowercase letters and \"-\"".to_string()
+        );
+    }
+
+    #[test]
+    fn test_strict_symbols() {
+        let rule = TypeCase {
+            strict: true,
+            ..Default::default()
+        };
+
+        let message = Message::new("hot-fix: resolve crash".to_string());
+        assert!(rule.validate(&message).is_none());
+
+        // The type is in lower case but contains a symbol which is not allowed.
+        let message = Message::new("hot_fix: resolve crash".to_string());
+        assert!(rule.validate(&message).is_some());
+        assert!(TypeCase::default().validate(&message).is_none());
+    }
+}
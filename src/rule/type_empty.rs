@@ -11,6 +11,11 @@ pub struct TypeEmpty {
     // Note that currently the default literal is not supported.
     // See: https://github.com/serde-rs/serde/issues/368
     level: Option<Level>,
+
+    /// Forbidden represents whether the type must be empty.
+    /// If it is false, the type must not be empty instead.
+    #[serde(default)]
+    forbidden: bool,
 }
 
 /// TypeEmpty represents the type-empty rule.
@@ -19,14 +24,24 @@ impl Rule for TypeEmpty {
     const LEVEL: Level = Level::Error;
 
     fn message(&self, _message: &Message) -> String {
-        "type is empty".to_string()
+        if self.forbidden {
+            return "type is not empty".to_string();
+        }
+
+        "type is empty. Prefix the subject with a conventional type such as \"feat: \"".to_string()
     }
 
     fn validate(&self, message: &Message) -> Option<Violation> {
-        if message.r#type.is_none() || message.r#type.as_ref().unwrap().is_empty() {
+        let is_empty = match &message.r#type {
+            None => true,
+            Some(r#type) => r#type.is_empty(),
+        };
+
+        if is_empty != self.forbidden {
             return Some(Violation {
                 level: self.level.unwrap_or(Self::LEVEL),
                 message: self.message(message),
+                rule: Self::NAME.to_string(),
             });
         }
 
@@ -39,6 +54,7 @@ impl Default for TypeEmpty {
     fn default() -> Self {
         Self {
             level: Some(Self::LEVEL),
+            forbidden: false,
         }
     }
 }
@@ -57,6 +73,7 @@ mod tests {
             r#type: Some("feat".to_string()),
             raw: "feat(scope): broadcast $destroy event on scope destruction".to_string(),
             scope: None,
+            separator: ":".to_string(),
             subject: None,
         };
 
@@ -73,12 +90,39 @@ mod tests {
             r#type: None,
             raw: "(scope): broadcast $destroy event on scope destruction".to_string(),
             scope: None,
+            separator: ":".to_string(),
             subject: None,
         };
 
         let violation = rule.validate(&message);
         assert!(violation.is_some());
         assert_eq!(violation.clone().unwrap().level, Level::Error);
-        assert_eq!(violation.unwrap().message, "type is empty".to_string());
+        assert_eq!(
+            violation.unwrap().message,
+            "type is empty. Prefix the subject with a conventional type such as \"feat: \""
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_forbidden_type() {
+        let rule = TypeEmpty {
+            forbidden: true,
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: None,
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(scope): broadcast $destroy event on scope destruction".to_string(),
+            scope: None,
+            separator: ":".to_string(),
+            subject: None,
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.unwrap().message, "type is not empty".to_string());
     }
 }
@@ -0,0 +1,182 @@
+use crate::{
+    message::Message,
+    result::Violation,
+    rule::{render_message, Rule},
+};
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// TypeEnum represents the type-enum rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TypeEnum {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Allowed represents the allowed types.
+    /// Defaults to the types of the conventional commits.
+    #[serde(default = "default_allowed")]
+    allowed: Vec<String>,
+
+    /// Message represents the custom message used instead of the default one.
+    /// `{found}` and `{expected}` are replaced with the found type and the allowed types.
+    message: Option<String>,
+}
+
+/// Types of the conventional commits.
+/// See: https://github.com/conventional-changelog/commitlint/tree/master/@commitlint/config-conventional
+fn default_allowed() -> Vec<String> {
+    [
+        "build", "chore", "ci", "docs", "feat", "fix", "perf", "refactor", "revert", "style",
+        "test",
+    ]
+    .iter()
+    .map(|t| t.to_string())
+    .collect()
+}
+
+/// TypeEnum represents the type-enum rule.
+impl Rule for TypeEnum {
+    const NAME: &'static str = "type-enum";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        if let Some(template) = &self.message {
+            let found = message.r#type.as_deref().unwrap_or_default();
+            return render_message(template, found, &format!("{:?}", self.allowed));
+        }
+
+        match &message.r#type {
+            Some(r#type) if !r#type.is_empty() => format!(
+                "type {} is not allowed. Only {:?} are allowed",
+                r#type, self.allowed
+            ),
+            _ => format!("type is missing. Only {:?} are allowed", self.allowed),
+        }
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        match &message.r#type {
+            Some(r#type) if self.allowed.contains(r#type) => None,
+            _ => Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                rule: Self::NAME.to_string(),
+            }),
+        }
+    }
+}
+
+/// Default implementation of TypeEnum.
+impl Default for TypeEnum {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            allowed: default_allowed(),
+            message: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_type() {
+        let rule = TypeEnum::default();
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag".to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_unknown_type() {
+        let rule = TypeEnum {
+            allowed: vec!["feat".to_string(), "fix".to_string()],
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feature".to_string()),
+            raw: "feature(cli): add new flag".to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feature(cli): add new flag".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "type feature is not allowed. Only [\"feat\", \"fix\"] are allowed".to_string()
+        );
+    }
+
+    #[test]
+    fn test_missing_type() {
+        let rule = TypeEnum {
+            allowed: vec!["feat".to_string(), "fix".to_string()],
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: None,
+            raw: "add new flag".to_string(),
+            scope: None,
+            separator: ":".to_string(),
+            subject: Some("add new flag".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "type is missing. Only [\"feat\", \"fix\"] are allowed".to_string()
+        );
+    }
+
+    #[test]
+    fn test_custom_message() {
+        let rule = TypeEnum {
+            allowed: vec!["feat".to_string(), "fix".to_string()],
+            message: Some("{found} は使えません。{expected} を使ってください".to_string()),
+            ..Default::default()
+        };
+        let message = Message {
+            body: None,
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("chore".to_string()),
+            raw: "chore(cli): add new flag".to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("chore(cli): add new flag".to_string()),
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(
+            violation.unwrap().message,
+            "chore は使えません。[\"feat\", \"fix\"] を使ってください".to_string()
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{message::Message, result::Violation, rule::Rule};
+use crate::{message::Message, pattern, result::Violation, rule::Rule};
 use serde::{Deserialize, Serialize};
 
 use super::Level;
@@ -30,12 +30,13 @@ impl Rule for TypeFormat {
 
     fn validate(&self, message: &Message) -> Option<Violation> {
         if let Some(format) = &self.format {
-            let regex = match regex::Regex::new(format) {
+            let regex = match pattern::compile(format) {
                 Ok(regex) => regex,
                 Err(err) => {
                     return Some(Violation {
                         level: self.level.unwrap_or(Self::LEVEL),
                         message: err.to_string(),
+                        rule: Self::NAME.to_string(),
                     });
                 }
             };
@@ -45,6 +46,7 @@ impl Rule for TypeFormat {
                     return Some(Violation {
                         level: self.level.unwrap_or(Self::LEVEL),
                         message: "found no type".to_string(),
+                        rule: Self::NAME.to_string(),
                     });
                 }
                 Some(description) => {
@@ -52,6 +54,7 @@ impl Rule for TypeFormat {
                         return Some(Violation {
                             level: self.level.unwrap_or(Self::LEVEL),
                             message: self.message(message),
+                            rule: Self::NAME.to_string(),
                         });
                     }
                 }
@@ -78,8 +81,10 @@ mod tests {
 
     #[test]
     fn test_invalid_description_format() {
-        let mut rule = TypeFormat::default();
-        rule.format = Some(r"^[a-z].*".to_string());
+        let rule = TypeFormat {
+            format: Some(r"^[a-z].*".to_string()),
+            ..Default::default()
+        };
 
         let message = Message {
             body: None,
@@ -88,6 +93,7 @@ mod tests {
             r#type: Some("feat".to_string()),
             raw: "feat(scope): Add new flag".to_string(),
             scope: Some("scope".to_string()),
+            separator: ":".to_string(),
             subject: None,
         };
 
@@ -96,8 +102,10 @@ mod tests {
 
     #[test]
     fn test_valid_description_format() {
-        let mut rule = TypeFormat::default();
-        rule.format = Some(r"^[a-z].*".to_string());
+        let rule = TypeFormat {
+            format: Some(r"^[a-z].*".to_string()),
+            ..Default::default()
+        };
 
         let message = Message {
             body: None,
@@ -106,6 +114,7 @@ mod tests {
             r#type: Some("Feat".to_string()),
             raw: "Feat(scope): Add new flag".to_string(),
             scope: Some("Scope".to_string()),
+            separator: ":".to_string(),
             subject: None,
         };
 
@@ -120,8 +129,10 @@ mod tests {
 
     #[test]
     fn test_invalid_regex() {
-        let mut rule = TypeFormat::default();
-        rule.format = Some(r"(".to_string());
+        let rule = TypeFormat {
+            format: Some(r"(".to_string()),
+            ..Default::default()
+        };
 
         let message = Message {
             body: None,
@@ -130,6 +141,7 @@ mod tests {
             r#type: Some("feat".to_string()),
             raw: "feat(scope): Invalid regex".to_string(),
             scope: Some("scope".to_string()),
+            separator: ":".to_string(),
             subject: None,
         };
 
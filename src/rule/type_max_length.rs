@@ -1,4 +1,4 @@
-use crate::{message::Message, result::Violation, rule::Rule};
+use crate::{message::Message, result::Violation, rule::Rule, width::display_width};
 use serde::{Deserialize, Serialize};
 
 use super::Level;
@@ -28,10 +28,11 @@ impl Rule for TypeMaxLength {
     fn validate(&self, message: &Message) -> Option<Violation> {
         match &message.r#type {
             Some(t) => {
-                if t.len() >= self.length {
+                if display_width(t) >= self.length {
                     return Some(Violation {
                         level: self.level.unwrap_or(Self::LEVEL),
                         message: self.message(message),
+                        rule: Self::NAME.to_string(),
                     });
                 }
             }
@@ -39,6 +40,7 @@ impl Rule for TypeMaxLength {
                 return Some(Violation {
                     level: self.level.unwrap_or(Self::LEVEL),
                     message: self.message(message),
+                    rule: Self::NAME.to_string(),
                 })
             }
         }
@@ -74,6 +76,7 @@ mod tests {
             r#type: Some("feat".to_string()),
             raw: "feat(scope): desc".to_string(),
             scope: Some("scope".to_string()),
+            separator: ":".to_string(),
             subject: Some("feat(scope): desc".to_string()),
         };
 
@@ -93,6 +96,7 @@ mod tests {
             r#type: Some("feat".to_string()),
             raw: "feat(scope): I'm long description".to_string(),
             scope: Some("scope".to_string()),
+            separator: ":".to_string(),
             subject: None,
         };
 
@@ -0,0 +1,236 @@
+use crate::{message::Message, pattern, result::Violation, rule::Rule};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::rc::Rc;
+
+use super::Level;
+
+/// WordBlocklist represents the word-blocklist rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WordBlocklist {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Words represents the words which must not appear in the message.
+    #[serde(default)]
+    words: Vec<String>,
+
+    /// CaseInsensitive represents whether the words are matched case-insensitively.
+    #[serde(default)]
+    case_insensitive: bool,
+
+    /// WholeWord represents whether only the whole words are matched,
+    /// so that the word inside a larger word (e.g. `pass` in `passed`) is not blocked.
+    #[serde(default = "default_whole_word")]
+    whole_word: bool,
+}
+
+/// Match the whole words by default to avoid false positives.
+fn default_whole_word() -> bool {
+    true
+}
+
+/// BlockedWord represents a blocked word found in the message.
+struct BlockedWord {
+    /// Word is the text found in the message.
+    word: String,
+
+    /// Line is the line number starting from 1.
+    line: usize,
+
+    /// Column is the column number in characters starting from 1.
+    column: usize,
+}
+
+impl WordBlocklist {
+    /// Build the pattern to find the word.
+    fn pattern(&self, word: &str) -> Rc<Regex> {
+        let mut regex = regex::escape(word);
+        if self.whole_word {
+            regex = format!(r"\b{}\b", regex);
+        }
+        if self.case_insensitive {
+            regex = format!("(?i){}", regex);
+        }
+
+        pattern::compile(&regex).expect("escaped word is a valid pattern")
+    }
+
+    /// Find the blocked words in the order of their location.
+    fn find(&self, message: &Message) -> Vec<BlockedWord> {
+        let patterns: Vec<Rc<Regex>> = self
+            .words
+            .iter()
+            .filter(|word| !word.is_empty())
+            .map(|word| self.pattern(word))
+            .collect();
+
+        let mut found = Vec::new();
+        for (i, line) in message.raw.lines().enumerate() {
+            let mut matches: Vec<_> = patterns
+                .iter()
+                .flat_map(|pattern| pattern.find_iter(line))
+                .collect();
+            matches.sort_by_key(|m| m.start());
+
+            for m in matches {
+                found.push(BlockedWord {
+                    word: m.as_str().to_string(),
+                    line: i + 1,
+                    column: line[..m.start()].chars().count() + 1,
+                });
+            }
+        }
+
+        found
+    }
+
+    /// Build the violation of the blocked word.
+    fn violation(&self, found: &BlockedWord) -> Violation {
+        Violation {
+            level: self.level.unwrap_or(Self::LEVEL),
+            message: format!(
+                "word {:?} is not allowed (line {}, column {})",
+                found.word, found.line, found.column
+            ),
+            rule: Self::NAME.to_string(),
+        }
+    }
+}
+
+/// WordBlocklist represents the word-blocklist rule.
+impl Rule for WordBlocklist {
+    const NAME: &'static str = "word-blocklist";
+    const LEVEL: Level = Level::Error;
+
+    fn message(&self, message: &Message) -> String {
+        match self.find(message).first() {
+            Some(found) => self.violation(found).message,
+            None => format!("words {:?} are not allowed", self.words),
+        }
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        self.validate_all(message).into_iter().next()
+    }
+
+    fn validate_all(&self, message: &Message) -> Vec<Violation> {
+        self.find(message)
+            .iter()
+            .map(|found| self.violation(found))
+            .collect()
+    }
+}
+
+/// Default implementation of WordBlocklist.
+impl Default for WordBlocklist {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            words: Vec::new(),
+            case_insensitive: false,
+            whole_word: default_whole_word(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_blocked_words() {
+        let rule = WordBlocklist {
+            words: vec!["password".to_string()],
+            ..Default::default()
+        };
+        let message = Message::new("feat(cli): add new flag".to_string());
+
+        assert!(rule.validate_all(&message).is_empty());
+    }
+
+    #[test]
+    fn test_blocked_word() {
+        let rule = WordBlocklist {
+            words: vec!["password".to_string()],
+            ..Default::default()
+        };
+        let message = Message {
+            body: Some("The password is hunter2.".to_string()),
+            description: Some("add new flag".to_string()),
+            footers: None,
+            r#type: Some("feat".to_string()),
+            raw: "feat(cli): add new flag
+
+The password is hunter2."
+                .to_string(),
+            scope: Some("cli".to_string()),
+            separator: ":".to_string(),
+            subject: Some("feat(cli): add new flag".to_string()),
+        };
+
+        let violations = rule.validate_all(&message);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].level, Level::Error);
+        assert_eq!(
+            violations[0].message,
+            "word \"password\" is not allowed (line 3, column 5)".to_string()
+        );
+    }
+
+    #[test]
+    fn test_whole_word() {
+        let rule = WordBlocklist {
+            words: vec!["pass".to_string()],
+            ..Default::default()
+        };
+        let message = Message::new("test: make the tests passed".to_string());
+
+        assert!(rule.validate_all(&message).is_empty());
+
+        let rule = WordBlocklist {
+            words: vec!["pass".to_string()],
+            whole_word: false,
+            ..Default::default()
+        };
+
+        let violations = rule.validate_all(&message);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            violations[0].message,
+            "word \"pass\" is not allowed (line 1, column 22)".to_string()
+        );
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        let message = Message::new("feat: remove the TODO and the todo".to_string());
+
+        let rule = WordBlocklist {
+            words: vec!["todo".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(rule.validate_all(&message).len(), 1);
+
+        let rule = WordBlocklist {
+            words: vec!["todo".to_string()],
+            case_insensitive: true,
+            ..Default::default()
+        };
+        let messages: Vec<String> = rule
+            .validate_all(&message)
+            .into_iter()
+            .map(|v| v.message)
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "word \"TODO\" is not allowed (line 1, column 18)".to_string(),
+                "word \"todo\" is not allowed (line 1, column 31)".to_string(),
+            ]
+        );
+    }
+}
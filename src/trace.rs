@@ -0,0 +1,305 @@
+//! Trace of the rules run for a commit message.
+//!
+//! The traces are only collected on demand, e.g. with the `--debug` flag of the CLI,
+//! so that the lint is not slowed down by the timing by default.
+use std::{
+    collections::HashMap,
+    fmt,
+    time::{Duration, Instant},
+};
+
+use crate::{config::Config, message::Message, result::Violation, rule::Level};
+
+/// Status represents the result of a rule.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Status {
+    /// The rule is not configured or disabled with the `off` level.
+    Disabled,
+
+    /// The rule ran and reported the violations only at the `ignore` level.
+    Ignored(Vec<Violation>),
+
+    /// The commit message is skipped by the `ignores` patterns or as a merge commit.
+    /// The batch rules are also skipped unless multiple commit messages are linted.
+    Skipped,
+
+    /// The rule ran and reported no violation.
+    Passed,
+
+    /// The rule ran and reported the violations.
+    Failed(Vec<Violation>),
+}
+
+/// RuleTrace represents the trace of a single rule.
+#[derive(Clone, Debug)]
+pub struct RuleTrace {
+    /// Name of the rule.
+    pub rule: String,
+
+    /// Result of the rule.
+    pub status: Status,
+
+    /// Time taken to run the rule.
+    /// It is zero if the rule did not run.
+    pub elapsed: Duration,
+}
+
+/// Format the trace as a single line such as `scope-empty: failed (1 violation) in 3µs`.
+impl fmt::Display for RuleTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.status {
+            Status::Disabled => write!(f, "{}: skipped (disabled)", self.rule),
+            Status::Ignored(violations) => write!(
+                f,
+                "{}: ignored ({} violation{}) in {:?}",
+                self.rule,
+                violations.len(),
+                if violations.len() == 1 { "" } else { "s" },
+                self.elapsed
+            ),
+            Status::Skipped => write!(f, "{}: skipped (message ignored)", self.rule),
+            Status::Passed => write!(f, "{}: passed in {:?}", self.rule, self.elapsed),
+            Status::Failed(violations) => write!(
+                f,
+                "{}: failed ({} violation{}) in {:?}",
+                self.rule,
+                violations.len(),
+                if violations.len() == 1 { "" } else { "s" },
+                self.elapsed
+            ),
+        }
+    }
+}
+
+/// Validate the commit message with the configuration and trace each rule.
+/// All the built-in rules are traced in ascending order of the name, including the disabled ones.
+/// Note that the batch rules are traced by `trace_batch` instead.
+///
+/// The rules run through `Config::validate_with`, so that the traced violations are the ones
+/// reported by the lint after `ignores`, `ignore-revert` and `severity` are applied.
+pub fn trace(message: &Message, config: &Config) -> Vec<RuleTrace> {
+    let mut elapsed = HashMap::new();
+    let violations = config.validate_with(message, |rule, message| {
+        let start = Instant::now();
+        let violations = rule.validate_all(message);
+        elapsed.insert(rule.name(), start.elapsed());
+        violations
+    });
+
+    let rules = config.rules.rules();
+    collect(
+        rules.iter().map(|(name, rule)| (*name, rule.is_some())),
+        &elapsed,
+        &violations,
+    )
+}
+
+/// Validate the commit messages together with the configuration and trace each batch rule.
+/// All the batch rules are traced in ascending order of the name, including the disabled ones.
+///
+/// The batch rules run through `Config::validate_batch_with` as the rules of `trace` do.
+pub fn trace_batch(messages: &[Message], config: &Config) -> Vec<RuleTrace> {
+    let mut elapsed = HashMap::new();
+    let violations = config.validate_batch_with(messages, |rule, messages| {
+        let start = Instant::now();
+        let violations = rule.validate_batch(messages);
+        elapsed.insert(rule.name(), start.elapsed());
+        violations
+    });
+
+    let rules = config.rules.batch_rules();
+    collect(
+        rules.iter().map(|(name, rule)| (*name, rule.is_some())),
+        &elapsed,
+        &violations,
+    )
+}
+
+/// Collect the traces of the rules given with whether they are configured,
+/// from the elapsed time of the rules which ran and the reported violations.
+fn collect<'a>(
+    rules: impl Iterator<Item = (&'a str, bool)>,
+    elapsed: &HashMap<&str, Duration>,
+    violations: &[Violation],
+) -> Vec<RuleTrace> {
+    let mut traces: Vec<RuleTrace> = rules
+        .map(|(name, configured)| {
+            let elapsed = elapsed.get(name).copied();
+            let status = match (configured, elapsed) {
+                (false, _) => Status::Disabled,
+                // The configured rule did not run as the commit messages are skipped.
+                (true, None) => Status::Skipped,
+                (true, Some(_)) => status(name, violations),
+            };
+
+            RuleTrace {
+                rule: name.to_string(),
+                status,
+                elapsed: elapsed.unwrap_or_default(),
+            }
+        })
+        .collect();
+    traces.sort_by(|a, b| a.rule.cmp(&b.rule));
+
+    traces
+}
+
+/// Get the status of the rule which ran from the reported violations.
+fn status(name: &str, violations: &[Violation]) -> Status {
+    let violations: Vec<Violation> = violations
+        .iter()
+        .filter(|violation| violation.rule == name)
+        .cloned()
+        .collect();
+
+    if violations.is_empty() {
+        Status::Passed
+    } else if violations
+        .iter()
+        .all(|violation| violation.level == Level::Ignore)
+    {
+        Status::Ignored(violations)
+    } else {
+        Status::Failed(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trace() {
+        let config: Config = serde_yaml::from_str(
+            "rules:
+  body-empty:
+    level: ignore
+  scope-empty:
+    level: error
+  type-empty:
+    level: error
+",
+        )
+        .unwrap();
+
+        let traces = trace(&Message::new("feat: add new flag".to_string()), &config);
+
+        let find = |name: &str| traces.iter().find(|trace| trace.rule == name).unwrap();
+        assert!(matches!(find("body-empty").status, Status::Ignored(_)));
+        assert_eq!(find("subject-case").status, Status::Disabled);
+        assert_eq!(find("type-empty").status, Status::Passed);
+        match &find("scope-empty").status {
+            Status::Failed(violations) => {
+                assert_eq!(violations[0].message, "scope is empty".to_string())
+            }
+            status => panic!("unexpected status: {:?}", status),
+        }
+        assert_eq!(traces.len(), config.rules.rules().len());
+    }
+
+    #[test]
+    fn test_trace_as_validate() {
+        let config: Config = serde_yaml::from_str(
+            "rules:
+  scope-empty:
+    level: error
+  type-empty:
+    level: error
+ignore-revert:
+  - type-empty
+severity:
+  scope-empty: warning
+",
+        )
+        .unwrap();
+        let message = Message::new("Revert \"add new flag\"".to_string());
+
+        let traces = trace(&message, &config);
+
+        let find = |name: &str| traces.iter().find(|trace| trace.rule == name).unwrap();
+        assert_eq!(find("type-empty").status, Status::Passed);
+        assert_eq!(
+            find("scope-empty").status,
+            Status::Failed(config.validate(&message))
+        );
+        match &find("scope-empty").status {
+            Status::Failed(violations) => assert_eq!(violations[0].level, Level::Warning),
+            status => panic!("unexpected status: {:?}", status),
+        }
+    }
+
+    #[test]
+    fn test_trace_ignored_message() {
+        let config: Config = serde_yaml::from_str(
+            "rules:
+  scope-empty:
+    level: error
+ignores:
+  - ^WIP
+",
+        )
+        .unwrap();
+
+        let traces = trace(&Message::new("WIP".to_string()), &config);
+
+        let trace = traces
+            .iter()
+            .find(|trace| trace.rule == "scope-empty")
+            .unwrap();
+        assert_eq!(trace.status, Status::Skipped);
+        assert_eq!(trace.to_string(), "scope-empty: skipped (message ignored)");
+    }
+
+    #[test]
+    fn test_trace_batch() {
+        let config: Config = serde_yaml::from_str(
+            "rules:
+  no-duplicate-subject:
+    level: error
+",
+        )
+        .unwrap();
+        let messages = vec![
+            Message::new("feat: add new flag".to_string()),
+            Message::new("feat: add new flag".to_string()),
+        ];
+
+        let traces = trace_batch(&messages, &config);
+
+        let find = |name: &str| traces.iter().find(|trace| trace.rule == name).unwrap();
+        assert_eq!(find("scope-case-consistency").status, Status::Disabled);
+        assert_eq!(
+            find("no-duplicate-subject").status,
+            Status::Failed(config.validate_batch(&messages))
+        );
+        assert_eq!(traces.len(), config.rules.batch_rules().len());
+
+        let traces = trace_batch(&messages[..1], &config);
+        assert_eq!(
+            traces
+                .iter()
+                .find(|trace| trace.rule == "no-duplicate-subject")
+                .unwrap()
+                .status,
+            Status::Skipped
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let trace = RuleTrace {
+            rule: "scope-empty".to_string(),
+            status: Status::Failed(vec![Violation {
+                level: Level::Error,
+                message: "scope is empty".to_string(),
+                rule: "scope-empty".to_string(),
+            }]),
+            elapsed: Duration::from_micros(3),
+        };
+
+        assert_eq!(
+            trace.to_string(),
+            "scope-empty: failed (1 violation) in 3µs"
+        );
+    }
+}
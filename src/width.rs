@@ -0,0 +1,100 @@
+/// Ranges of the characters which are displayed in two columns.
+/// It covers the East Asian Wide and Fullwidth characters and the emoji.
+/// See: https://www.unicode.org/reports/tr11/
+const WIDE: [(u32, u32); 18] = [
+    (0x1100, 0x115F),   // Hangul Jamo
+    (0x2E80, 0x303E),   // CJK Radicals, Kangxi Radicals, CJK Symbols and Punctuation
+    (0x3041, 0x33FF),   // Hiragana, Katakana, Bopomofo, Hangul Compatibility Jamo, ...
+    (0x3400, 0x4DBF),   // CJK Unified Ideographs Extension A
+    (0x4E00, 0x9FFF),   // CJK Unified Ideographs
+    (0xA000, 0xA4CF),   // Yi Syllables and Radicals
+    (0xAC00, 0xD7A3),   // Hangul Syllables
+    (0xF900, 0xFAFF),   // CJK Compatibility Ideographs
+    (0xFE30, 0xFE4F),   // CJK Compatibility Forms
+    (0xFF00, 0xFF60),   // Fullwidth Forms
+    (0xFFE0, 0xFFE6),   // Fullwidth Signs
+    (0x1F300, 0x1F5FF), // Miscellaneous Symbols and Pictographs
+    (0x1F600, 0x1F64F), // Emoticons
+    (0x1F680, 0x1F6FF), // Transport and Map Symbols
+    (0x1F900, 0x1F9FF), // Supplemental Symbols and Pictographs
+    (0x1FA70, 0x1FAFF), // Symbols and Pictographs Extended-A
+    (0x20000, 0x2FFFD), // CJK Unified Ideographs Extension B..F
+    (0x30000, 0x3FFFD), // CJK Unified Ideographs Extension G
+];
+
+/// Ranges of the characters which are not displayed by themselves
+/// but combined with the previous character.
+const ZERO: [(u32, u32); 6] = [
+    (0x0300, 0x036F),   // Combining Diacritical Marks
+    (0x200B, 0x200F),   // Zero width space, joiners and marks
+    (0x20D0, 0x20FF),   // Combining Diacritical Marks for Symbols
+    (0xFE00, 0xFE0F),   // Variation Selectors
+    (0x1F3FB, 0x1F3FF), // Emoji Modifiers (skin tones)
+    (0xE0100, 0xE01EF), // Variation Selectors Supplement
+];
+
+/// Check whether the character is in one of the ranges.
+fn in_ranges(c: char, ranges: &[(u32, u32)]) -> bool {
+    let c = c as u32;
+    ranges.iter().any(|(start, end)| *start <= c && c <= *end)
+}
+
+/// Width of the character in columns.
+fn char_width(c: char) -> usize {
+    if in_ranges(c, &ZERO) {
+        0
+    } else if in_ranges(c, &WIDE) {
+        2
+    } else {
+        1
+    }
+}
+
+/// Get the length of the text as displayed in a terminal.
+///
+/// Unlike `str::len`, it does not count the bytes but the characters.
+/// Note that the wide characters such as CJK ideographs and emoji are counted as two columns,
+/// and the combining characters are not counted.
+pub fn display_width(text: &str) -> usize {
+    text.chars().map(char_width).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ascii() {
+        let text = "add new flag";
+        assert_eq!(text.len(), 12);
+        assert_eq!(display_width(text), 12);
+    }
+
+    #[test]
+    fn test_emoji() {
+        let text = "add 🎉";
+        assert_eq!(text.len(), 8);
+        assert_eq!(display_width(text), 6);
+    }
+
+    #[test]
+    fn test_emoji_with_modifier() {
+        let text = "👍🏽";
+        assert_eq!(text.len(), 8);
+        assert_eq!(display_width(text), 2);
+    }
+
+    #[test]
+    fn test_chinese() {
+        let text = "添加新标志";
+        assert_eq!(text.len(), 15);
+        assert_eq!(display_width(text), 10);
+    }
+
+    #[test]
+    fn test_accented() {
+        let text = "cafe\u{0301}";
+        assert_eq!(text.len(), 6);
+        assert_eq!(display_width(text), 4);
+    }
+}
@@ -0,0 +1,619 @@
+use std::{
+    fs,
+    io::Write,
+    path::PathBuf,
+    process::{Command, Output, Stdio},
+};
+
+/// Create the config file in a temporary directory.
+fn config_file(name: &str, content: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("commitlint-cli-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+
+    let path = dir.join(".commitlintrc.yaml");
+    fs::write(&path, content).unwrap();
+    path
+}
+
+/// Run the CLI with the input from stdin.
+fn run(args: &[&str], input: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_commitlint"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn test_batch() {
+    let config = config_file(
+        "batch",
+        "rules:
+  scope-empty:
+    level: error
+",
+    );
+
+    let output = run(
+        &["--batch", "--config", config.to_str().unwrap()],
+        "feat(cli): add new flag\n\0\nfix: typo\n\0\n",
+    );
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "commit 2: scope is empty\n"
+    );
+}
+
+#[test]
+fn test_batch_without_errors() {
+    let config = config_file(
+        "batch-without-errors",
+        "rules:
+  scope-empty:
+    level: warning
+",
+    );
+
+    let output = run(
+        &["--batch", "--config", config.to_str().unwrap()],
+        "feat(cli): add new flag\n\0\nfix: typo\n\0\n",
+    );
+
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "commit 2: scope is empty\n✖ 1 problem (0 errors, 1 warning)\n"
+    );
+}
+
+#[test]
+fn test_strict() {
+    let config = config_file(
+        "strict",
+        "rules:
+  scope-empty:
+    level: warning
+",
+    );
+
+    let output = run(&["--config", config.to_str().unwrap()], "fix: typo\n");
+    assert_eq!(output.status.code(), Some(0));
+
+    let output = run(
+        &["--strict", "--config", config.to_str().unwrap()],
+        "fix: typo\n",
+    );
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "scope is empty\n✖ 1 problem (0 errors, 1 warning)\n"
+    );
+}
+
+#[test]
+fn test_strict_config() {
+    let config = config_file(
+        "strict-config",
+        "strict: true
+rules:
+  scope-empty:
+    level: warning
+",
+    );
+
+    let output = run(&["--config", config.to_str().unwrap()], "fix: typo\n");
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn test_group_by_level() {
+    let config = config_file(
+        "group-by-level",
+        "rules:
+  body-empty:
+    level: warning
+  scope-empty:
+    level: error
+",
+    );
+
+    let output = run(
+        &[
+            "--batch",
+            "--group-by-level",
+            "--config",
+            config.to_str().unwrap(),
+        ],
+        "feat(cli): add new flag\n\0\nfix: typo\n\0\n",
+    );
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "errors:\n  commit 2: scope is empty\n"
+    );
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "warnings:\n  commit 1: body is empty\n  commit 2: body is empty\n✖ 3 problems (1 error, 2 warnings)\n"
+    );
+}
+
+#[test]
+fn test_exit_code_success() {
+    let output = run(&[], "feat(cli): add new flag\n");
+
+    assert_eq!(output.status.code(), Some(0));
+}
+
+#[test]
+fn test_exit_code_failure() {
+    let output = run(&[], "add new flag\n");
+
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn test_exit_code_config() {
+    let config = config_file(
+        "exit-code-config",
+        "rules:
+  scope-empty: [
+",
+    );
+
+    let output = run(
+        &["--config", config.to_str().unwrap()],
+        "feat(cli): add new flag\n",
+    );
+
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("Failed to load config: "));
+}
+
+#[test]
+fn test_exit_code_usage() {
+    let output = run(&["--no-such-flag"], "feat(cli): add new flag\n");
+
+    assert_eq!(output.status.code(), Some(3));
+
+    let output = run(&["--help"], "");
+    assert_eq!(output.status.code(), Some(0));
+}
+
+#[test]
+fn test_exit_code_usage_missing_edit_file() {
+    let output = run(
+        &["--edit", "/nonexistent/COMMIT_EDITMSG"],
+        "feat(cli): add new flag\n",
+    );
+
+    assert_eq!(output.status.code(), Some(3));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.starts_with(
+            "Failed to read commit messages: cannot read '/nonexistent/COMMIT_EDITMSG': "
+        ),
+        "{}",
+        stderr
+    );
+    assert!(!stderr.contains("panicked"), "{}", stderr);
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_exit_code_usage_unwritable_fix() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_commitlint"))
+        .arg("--fix")
+        .stdin(Stdio::piped())
+        .stdout(fs::File::create("/dev/full").unwrap())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(b"feat(cli): add new flag\n")
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    assert_eq!(output.status.code(), Some(3));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.starts_with("Failed to write fixed commit messages: "),
+        "{}",
+        stderr
+    );
+}
+
+#[test]
+fn test_exit_code_usage_unknown_range() {
+    let output = run(&["--range", "1111111..2222222"], "");
+
+    assert_eq!(output.status.code(), Some(3));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.starts_with("Failed to read commit messages: git log failed: "),
+        "{}",
+        stderr
+    );
+}
+
+#[test]
+fn test_batch_rule() {
+    let config = config_file(
+        "batch-rule",
+        "rules:
+  scope-case-consistency:
+    level: error
+",
+    );
+
+    let output = run(
+        &["--batch", "--config", config.to_str().unwrap()],
+        "feat(api): add new endpoint\n\0\nfeat(API): add another endpoint\n\0\n",
+    );
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "scope API differs only in case from api\n"
+    );
+}
+
+#[test]
+fn test_batch_rule_json() {
+    let config = config_file(
+        "batch-rule-json",
+        "rules:
+  no-duplicate-subject:
+    level: error
+",
+    );
+
+    let output = run(
+        &[
+            "--batch",
+            "--format",
+            "json",
+            "--config",
+            config.to_str().unwrap(),
+        ],
+        "feat: a\n\0feat: a\n\0",
+    );
+
+    assert_eq!(output.status.code(), Some(1));
+    let reports: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let reports = reports.as_array().unwrap();
+    assert_eq!(reports.len(), 3);
+    assert_eq!(reports[0]["valid"], true);
+    assert_eq!(reports[1]["valid"], true);
+    assert_eq!(reports[2]["batch"], true);
+    assert_eq!(reports[2]["valid"], false);
+    assert_eq!(reports[2]["errors"], 1);
+    assert_eq!(reports[2]["violations"][0]["rule"], "no-duplicate-subject");
+}
+
+#[test]
+fn test_batch_rule_junit() {
+    let config = config_file(
+        "batch-rule-junit",
+        "rules:
+  no-duplicate-subject:
+    level: error
+",
+    );
+
+    let output = run(
+        &[
+            "--batch",
+            "--format",
+            "junit",
+            "--config",
+            config.to_str().unwrap(),
+        ],
+        "feat: a\n\0feat: a\n\0",
+    );
+
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains(r#"<testsuites name="commitlint" tests="1" failures="1">"#),
+        "{}",
+        stdout
+    );
+    assert!(
+        stdout.contains(r#"<testsuite name="batch" tests="1" failures="1">"#),
+        "{}",
+        stdout
+    );
+    assert!(
+        stdout.contains(r#"<failure type="no-duplicate-subject""#),
+        "{}",
+        stdout
+    );
+}
+
+#[test]
+fn test_color() {
+    let config = config_file(
+        "color",
+        "rules:
+  scope-empty:
+    level: error
+",
+    );
+
+    let output = run(
+        &["--color", "--config", config.to_str().unwrap()],
+        "fix: typo\n",
+    );
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "\x1b[31mscope is empty\x1b[0m \x1b[2m[scope-empty]\x1b[0m\n"
+    );
+}
+
+#[test]
+fn test_no_color() {
+    let config = config_file(
+        "no-color",
+        "rules:
+  scope-empty:
+    level: error
+",
+    );
+
+    let output = run(
+        &["--no-color", "--config", config.to_str().unwrap()],
+        "fix: typo\n",
+    );
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "scope is empty\n");
+}
+
+#[test]
+fn test_summary() {
+    let config = config_file(
+        "summary",
+        "rules:
+  body-empty:
+    level: warning
+  scope-empty:
+    level: error
+",
+    );
+
+    let output = run(&["--config", config.to_str().unwrap()], "fix: typo\n");
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "body is empty\n✖ 2 problems (1 error, 1 warning)\n"
+    );
+}
+
+#[test]
+fn test_summary_without_problems() {
+    let config = config_file(
+        "summary-without-problems",
+        "rules:
+  scope-empty:
+    level: error
+",
+    );
+
+    let output = run(&["--config", config.to_str().unwrap()], "fix(cli): typo\n");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "");
+
+    let output = run(
+        &["--verbose", "--config", config.to_str().unwrap()],
+        "fix(cli): typo\n",
+    );
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "✔ no problems found\n"
+    );
+}
+
+#[test]
+fn test_quiet() {
+    let config = config_file(
+        "quiet",
+        "rules:
+  body-empty:
+    level: warning
+  scope-empty:
+    level: error
+",
+    );
+
+    let output = run(
+        &["--quiet", "--config", config.to_str().unwrap()],
+        "fix: typo\n",
+    );
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "");
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "");
+}
+
+#[test]
+fn test_edit_file() {
+    let config = config_file(
+        "edit-file",
+        "rules:
+  scope-empty:
+    level: error
+",
+    );
+    let path = config.with_file_name("COMMIT_EDITMSG");
+    fs::write(
+        &path,
+        "fix: typo
+# Please enter the commit message for your changes.
+",
+    )
+    .unwrap();
+
+    let output = run(
+        &[
+            "--config",
+            config.to_str().unwrap(),
+            "--edit",
+            path.to_str().unwrap(),
+        ],
+        "feat(cli): add new flag\n",
+    );
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "scope is empty\n");
+}
+
+#[test]
+fn test_fix_stdout() {
+    let config = config_file(
+        "fix-stdout",
+        "rules:
+  body-empty:
+    level: warning
+  no-trailing-whitespace:
+    level: error
+  subject-full-stop:
+    level: error
+",
+    );
+
+    let output = run(
+        &["--fix", "--no-color", "--config", config.to_str().unwrap()],
+        "feat: added thing.  \n",
+    );
+
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(output.stdout, b"feat: added thing\n");
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "body is empty\n✖ 1 problem (0 errors, 1 warning)\n"
+    );
+
+    let output = run(
+        &[
+            "--fix",
+            "--batch",
+            "--format",
+            "json",
+            "--config",
+            config.to_str().unwrap(),
+        ],
+        "feat: a. \n\0fix: b.\n\0",
+    );
+
+    assert_eq!(output.stdout, b"feat: a\n\0fix: b\n\0");
+    let reports: serde_json::Value = serde_json::from_slice(&output.stderr).unwrap();
+    assert_eq!(reports.as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn test_debug() {
+    let config = config_file(
+        "debug",
+        "rules:
+  scope-empty:
+    level: error
+  type-empty:
+    level: error
+",
+    );
+
+    let output = run(
+        &[
+            "--debug",
+            "--no-color",
+            "--config",
+            config.to_str().unwrap(),
+        ],
+        "feat: add new flag\n",
+    );
+
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let lines: Vec<&str> = stderr.lines().collect();
+    assert_eq!(lines[0], "debug: commit 1");
+    assert!(lines.contains(&"debug:   body-empty: skipped (disabled)"));
+    assert!(lines
+        .iter()
+        .any(|line| line.starts_with("debug:   scope-empty: failed (1 violation) in ")));
+    assert!(lines
+        .iter()
+        .any(|line| line.starts_with("debug:   type-empty: passed in ")));
+    assert_eq!(lines.last(), Some(&"scope is empty"));
+}
+
+#[test]
+fn test_debug_batch() {
+    let config = config_file(
+        "debug-batch",
+        "rules:
+  no-duplicate-subject:
+    level: error
+",
+    );
+
+    let output = run(
+        &[
+            "--batch",
+            "--debug",
+            "--no-color",
+            "--config",
+            config.to_str().unwrap(),
+        ],
+        "feat: add new flag\n\0feat: add new flag\n\0",
+    );
+
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let lines: Vec<&str> = stderr.lines().collect();
+    assert!(lines.contains(&"debug: batch"));
+    assert!(lines
+        .iter()
+        .any(|line| line.starts_with("debug:   no-duplicate-subject: failed (1 violation) in ")));
+    assert!(lines.contains(&"debug:   scope-case-consistency: skipped (disabled)"));
+}
+
+#[test]
+fn test_without_debug() {
+    let config = config_file(
+        "without-debug",
+        "rules:
+  scope-empty:
+    level: error
+",
+    );
+
+    let output = run(
+        &["--no-color", "--config", config.to_str().unwrap()],
+        "feat: add new flag\n",
+    );
+
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "scope is empty\n");
+}
@@ -0,0 +1,214 @@
+use commitlint_rs::{
+    config::Config, lint, lint_across, lint_batch, message::Message, result::LintResult,
+    result::Violation, rule::Level,
+};
+
+#[test]
+fn test_lint_collects_all_violations() {
+    let config: Config = serde_yaml::from_str(
+        "rules:
+  body-empty:
+    level: warning
+  scope-empty:
+    level: error
+  type:
+    level: error
+    options:
+      - feat
+      - fix
+",
+    )
+    .unwrap();
+
+    let result = lint("chore: update dependencies", &config);
+
+    assert!(!result.valid);
+
+    let rules: Vec<&str> = result.errors.iter().map(|v| v.rule.as_str()).collect();
+    assert_eq!(rules, vec!["scope-empty", "type"]);
+    assert!(result.errors.iter().all(|v| v.level == Level::Error));
+    assert_eq!(result.errors[0].message, "scope is empty".to_string());
+    assert_eq!(
+        result.errors[1].message,
+        "type chore is not allowed. Only [\"feat\", \"fix\"] are allowed".to_string()
+    );
+
+    assert_eq!(result.warnings.len(), 1);
+    assert_eq!(result.warnings[0].level, Level::Warning);
+    assert_eq!(result.warnings[0].message, "body is empty".to_string());
+}
+
+#[test]
+fn test_lint_without_violations() {
+    let config = Config::default();
+
+    let result = lint("feat(cli): add new flag", &config);
+
+    assert!(result.valid);
+    assert!(result.errors.is_empty());
+    assert!(result.warnings.is_empty());
+}
+
+#[test]
+fn test_lint_with_only_warnings_passes() {
+    let config: Config = serde_yaml::from_str(
+        "rules:
+  body-empty:
+    level: warning
+  scope-empty:
+    level: warning
+",
+    )
+    .unwrap();
+
+    let result = lint("feat: add new flag", &config);
+
+    assert!(result.valid);
+    assert!(result.errors.is_empty());
+
+    let rules: Vec<&str> = result.warnings.iter().map(|v| v.rule.as_str()).collect();
+    assert_eq!(rules, vec!["body-empty", "scope-empty"]);
+    assert!(result.warnings.iter().all(|v| v.level == Level::Warning));
+}
+
+#[test]
+fn test_lint_with_errors_fails() {
+    let config: Config = serde_yaml::from_str(
+        "rules:
+  body-empty:
+    level: warning
+  scope-empty:
+    level: error
+",
+    )
+    .unwrap();
+
+    let result = lint("feat: add new flag", &config);
+
+    assert!(!result.valid);
+    assert_eq!(result.errors.len(), 1);
+    assert_eq!(result.warnings.len(), 1);
+}
+
+#[test]
+fn test_lint_result_classifies_violations() {
+    let config: Config = serde_yaml::from_str(
+        "rules:
+  body-empty:
+    level: warning
+  scope-empty:
+    level: error
+",
+    )
+    .unwrap();
+
+    let result = lint("feat: add new flag", &config);
+
+    assert!(!result.valid);
+    assert_eq!(result.errors.len(), 1);
+    assert_eq!(result.errors[0].rule, "scope-empty".to_string());
+    assert_eq!(result.warnings.len(), 1);
+    assert_eq!(result.warnings[0].rule, "body-empty".to_string());
+}
+
+#[test]
+fn test_lint_with_disabled_rules() {
+    let config: Config = serde_yaml::from_str(
+        "rules:
+  description-empty:
+    level: off
+  subject-empty:
+    level: off
+  type-empty:
+    level: off
+  scope-empty:
+    level: error
+",
+    )
+    .unwrap();
+
+    assert!(config.rules.type_empty.is_none());
+
+    let result = lint("add new flag", &config);
+
+    assert!(!result.valid);
+    let rules: Vec<&str> = result.errors.iter().map(|v| v.rule.as_str()).collect();
+    assert_eq!(rules, vec!["scope-empty"]);
+    assert!(result.warnings.is_empty());
+}
+
+#[test]
+fn test_lint_batch_matches_sequential_lint() {
+    let config: Config = serde_yaml::from_str(
+        "rules:
+  body-empty:
+    level: warning
+  scope-empty:
+    level: error
+  subject-max-length:
+    level: error
+    length: 30
+",
+    )
+    .unwrap();
+
+    let raws: Vec<String> = (0..500)
+        .map(|i| match i % 4 {
+            0 => format!("feat(cli): add flag {}", i),
+            1 => format!("fix: resolve the issue number {} in the parser", i),
+            2 => format!("chore(deps): bump version {}\n\nUpdate the lock file.", i),
+            _ => format!("update {}", i),
+        })
+        .collect();
+    let messages: Vec<Message> = raws.iter().map(|raw| Message::new(raw.clone())).collect();
+
+    let sequential: Vec<LintResult> = raws.iter().map(|raw| lint(raw, &config)).collect();
+    let classify = |reports: Vec<Vec<Violation>>| -> Vec<LintResult> {
+        reports.into_iter().map(LintResult::from).collect()
+    };
+
+    assert_eq!(classify(lint_batch(&messages, &config, 1)), sequential);
+    assert_eq!(classify(lint_batch(&messages, &config, 8)), sequential);
+    assert_eq!(classify(lint_batch(&messages, &config, 0)), sequential);
+}
+
+#[test]
+fn test_lint_across_runs_batch_rules() {
+    let config: Config = serde_yaml::from_str(
+        "rules:
+  no-duplicate-subject:
+    level: error
+  scope-case-consistency:
+    level: warning
+",
+    )
+    .unwrap();
+
+    let messages: Vec<Message> = [
+        "feat(api): add new endpoint",
+        "feat(API): add new endpoint",
+        "feat(api): add new endpoint",
+    ]
+    .iter()
+    .map(|raw| Message::new(raw.to_string()))
+    .collect();
+
+    let violations = lint_across(&messages, &config);
+    let rules: Vec<&str> = violations.iter().map(|v| v.rule.as_str()).collect();
+    assert_eq!(
+        rules,
+        vec!["no-duplicate-subject", "scope-case-consistency"]
+    );
+    assert_eq!(violations[0].level, Level::Error);
+    assert_eq!(violations[1].level, Level::Warning);
+
+    // A single commit message has nothing to compare with.
+    assert!(lint_across(&messages[..1], &config).is_empty());
+}
+
+#[test]
+fn test_config_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<Config>();
+}